@@ -0,0 +1,191 @@
+use std::fmt::Write;
+
+use crate::{
+    enums::{Base, Condition},
+    internal_macros::push_clause,
+    traits::{IntoStatement, IntoTimeout},
+    types::access::{AccessCommand, AccessData, GrantFilter, GrantSubject, PurgeTarget},
+};
+
+/// Entry point for `ACCESS` statements managing bearer grants, created via
+/// [`QueryBuilder::access`].
+///
+/// Only available when targeting SurrealDB 2.0 or newer:
+///
+/// ```compile_fail
+/// use surrealex::{QueryBuilder, SurrealV1};
+///
+/// QueryBuilder::with_version(SurrealV1).access("api");
+/// ```
+///
+/// [`QueryBuilder::access`]: crate::QueryBuilder::access
+pub struct AccessBuilder {
+    pub(crate) name: String,
+    pub(crate) base: Option<Base>,
+}
+
+impl AccessBuilder {
+    /// Sets the level the access method is defined on (`ON ROOT | NAMESPACE | DATABASE`).
+    pub fn on(mut self, base: Base) -> Self {
+        self.base = Some(base);
+        self
+    }
+
+    fn command(self, command: AccessCommand) -> AccessReady {
+        AccessReady {
+            data: self.into_data(command),
+        }
+    }
+
+    fn into_data(self, command: AccessCommand) -> AccessData {
+        AccessData {
+            name: self.name,
+            base: self.base,
+            command,
+        }
+    }
+
+    /// Issues a grant for a system user: `GRANT FOR USER @user`.
+    ///
+    /// # Example
+    /// ```
+    /// # use surrealex::QueryBuilder;
+    /// use surrealex::enums::Base;
+    ///
+    /// let sql = QueryBuilder::access("api").on(Base::Database).grant_for_user("ci").build();
+    /// assert_eq!(sql, "ACCESS api ON DATABASE GRANT FOR USER ci");
+    /// ```
+    pub fn grant_for_user(self, user: &str) -> AccessReady {
+        self.command(AccessCommand::Grant(GrantSubject::User(user.to_string())))
+    }
+
+    /// Issues a grant for a record: `GRANT FOR RECORD @record`.
+    pub fn grant_for_record(self, record: &str) -> AccessReady {
+        self.command(AccessCommand::Grant(GrantSubject::Record(
+            record.to_string(),
+        )))
+    }
+
+    /// Lists every grant: `SHOW ALL`.
+    pub fn show_all(self) -> AccessReady {
+        self.command(AccessCommand::Show(GrantFilter::All))
+    }
+
+    /// Shows a single grant: `SHOW GRANT @id`.
+    pub fn show_grant(self, id: &str) -> AccessReady {
+        self.command(AccessCommand::Show(GrantFilter::Grant(id.to_string())))
+    }
+
+    /// Lists the grants matching a condition: `SHOW WHERE @condition`.
+    pub fn show_where<T: Into<Condition>>(self, condition: T) -> AccessReady {
+        self.command(AccessCommand::Show(GrantFilter::Where(condition.into())))
+    }
+
+    /// Revokes every grant: `REVOKE ALL`.
+    pub fn revoke_all(self) -> AccessReady {
+        self.command(AccessCommand::Revoke(GrantFilter::All))
+    }
+
+    /// Revokes a single grant: `REVOKE GRANT @id`.
+    pub fn revoke_grant(self, id: &str) -> AccessReady {
+        self.command(AccessCommand::Revoke(GrantFilter::Grant(id.to_string())))
+    }
+
+    /// Revokes the grants matching a condition: `REVOKE WHERE @condition`.
+    pub fn revoke_where<T: Into<Condition>>(self, condition: T) -> AccessReady {
+        self.command(AccessCommand::Revoke(GrantFilter::Where(condition.into())))
+    }
+
+    /// Deletes expired and/or revoked grants: `PURGE EXPIRED | REVOKED`.
+    ///
+    /// Use [`AccessPurgeReady::grace`] to only purge grants that expired or
+    /// were revoked longer ago than a given duration.
+    pub fn purge(self, target: PurgeTarget) -> AccessPurgeReady {
+        AccessPurgeReady {
+            data: self.into_data(AccessCommand::Purge {
+                target,
+                grace: None,
+            }),
+        }
+    }
+}
+
+/// An ACCESS statement with its `GRANT`, `SHOW` or `REVOKE` command set,
+/// ready to be built.
+///
+/// A grace period only applies to `PURGE` commands:
+///
+/// ```compile_fail
+/// # use surrealex::QueryBuilder;
+/// QueryBuilder::access("api").revoke_all().grace("1d");
+/// ```
+pub struct AccessReady {
+    pub data: AccessData,
+}
+
+impl AccessReady {
+    /// Builds the final ACCESS query string.
+    pub fn build(self) -> String {
+        render_access(&self.data)
+    }
+}
+
+impl IntoStatement for AccessReady {
+    fn into_statement(self) -> String {
+        self.build()
+    }
+}
+
+/// An ACCESS statement with a `PURGE` command, ready to be built.
+pub struct AccessPurgeReady {
+    pub data: AccessData,
+}
+
+impl AccessPurgeReady {
+    /// Adds a `FOR @duration` grace period, only purging grants that expired
+    /// or were revoked longer ago than `duration`.
+    ///
+    /// # Example
+    /// ```
+    /// # use surrealex::QueryBuilder;
+    /// use surrealex::types::access::PurgeTarget;
+    ///
+    /// let sql = QueryBuilder::access("api")
+    ///     .purge(PurgeTarget::Expired)
+    ///     .grace("90d")
+    ///     .build();
+    /// assert_eq!(sql, "ACCESS api PURGE EXPIRED FOR 90d");
+    /// ```
+    pub fn grace(mut self, duration: impl IntoTimeout) -> Self {
+        if let AccessCommand::Purge { grace, .. } = &mut self.data.command {
+            *grace = Some(duration.into_timeout());
+        }
+        self
+    }
+
+    /// Builds the final ACCESS query string.
+    pub fn build(self) -> String {
+        render_access(&self.data)
+    }
+}
+
+impl IntoStatement for AccessPurgeReady {
+    fn into_statement(self) -> String {
+        self.build()
+    }
+}
+
+fn render_access(data: &AccessData) -> String {
+    let mut query = String::with_capacity(64);
+    let name = &data.name;
+    push_clause!(query, "ACCESS {name}");
+
+    if let Some(ref base) = data.base {
+        push_clause!(query, "ON {base}");
+    }
+
+    let command = &data.command;
+    push_clause!(query, "{command}");
+
+    query
+}
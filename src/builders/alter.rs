@@ -0,0 +1,153 @@
+use std::fmt::Write;
+
+use crate::{
+    internal_macros::push_clause,
+    internal_utils::quote_str,
+    traits::{IntoStatement, IntoTimeout},
+    types::{
+        alter::AlterTableData,
+        define::{
+            Changefeed, Permission, PermissionKind, PermissionRule, Permissions, table::SchemaMode,
+        },
+    },
+};
+
+/// Builder for `ALTER TABLE` statements.
+///
+/// Only available when targeting SurrealDB 3.0 or newer, through
+/// [`VersionedQueryBuilder::alter_table`](crate::VersionedQueryBuilder::alter_table):
+///
+/// ```
+/// use surrealex::{QueryBuilder, SurrealV3};
+///
+/// let sql = QueryBuilder::with_version(SurrealV3)
+///     .alter_table("person")
+///     .schemafull()
+///     .comment("people")
+///     .build();
+/// assert_eq!(sql, "ALTER TABLE person SCHEMAFULL COMMENT \"people\"");
+/// ```
+///
+/// ```compile_fail
+/// use surrealex::{QueryBuilder, SurrealV2};
+///
+/// QueryBuilder::with_version(SurrealV2).alter_table("person");
+/// ```
+pub struct AlterTableBuilder {
+    pub data: AlterTableData,
+}
+
+impl AlterTableBuilder {
+    /// Adds `IF EXISTS`, turning the statement into a no-op for missing tables.
+    pub fn if_exists(mut self) -> Self {
+        self.data.if_exists = true;
+        self
+    }
+
+    /// Switches the table to `SCHEMAFULL`.
+    pub fn schemafull(mut self) -> Self {
+        self.data.schema = Some(SchemaMode::Schemafull);
+        self
+    }
+
+    /// Switches the table to `SCHEMALESS`.
+    pub fn schemaless(mut self) -> Self {
+        self.data.schema = Some(SchemaMode::Schemaless);
+        self
+    }
+
+    /// Sets the `CHANGEFEED` clause with the given retention duration.
+    pub fn changefeed(mut self, duration: impl IntoTimeout) -> Self {
+        self.data.changefeed = Some(Changefeed {
+            duration: duration.into_timeout(),
+            include_original: false,
+        });
+        self
+    }
+
+    /// Adds `INCLUDE ORIGINAL` to the `CHANGEFEED` clause.
+    ///
+    /// Has no effect unless [`changefeed`](Self::changefeed) was called first.
+    pub fn include_original(mut self) -> Self {
+        if let Some(changefeed) = &mut self.data.changefeed {
+            changefeed.include_original = true;
+        }
+        self
+    }
+
+    /// Sets the PERMISSIONS clause to `PERMISSIONS NONE`.
+    pub fn permissions_none(mut self) -> Self {
+        self.data.permissions = Some(Permissions::None);
+        self
+    }
+
+    /// Sets the PERMISSIONS clause to `PERMISSIONS FULL`.
+    pub fn permissions_full(mut self) -> Self {
+        self.data.permissions = Some(Permissions::Full);
+        self
+    }
+
+    /// Adds a `FOR @kinds @permission` rule to the PERMISSIONS clause.
+    ///
+    /// Multiple calls accumulate rules. A previous `NONE` or `FULL` clause is
+    /// replaced.
+    pub fn permissions_for(
+        mut self,
+        kinds: Vec<PermissionKind>,
+        permission: impl Into<Permission>,
+    ) -> Self {
+        let rule = PermissionRule {
+            kinds,
+            permission: permission.into(),
+        };
+        match &mut self.data.permissions {
+            Some(Permissions::For(rules)) => rules.push(rule),
+            _ => self.data.permissions = Some(Permissions::For(vec![rule])),
+        }
+        self
+    }
+
+    /// Sets the `COMMENT` clause. The text is rendered as a quoted string.
+    pub fn comment(mut self, comment: &str) -> Self {
+        self.data.comment = Some(comment.to_string());
+        self
+    }
+
+    /// Builds the final ALTER TABLE query string.
+    pub fn build(self) -> String {
+        let mut query = String::with_capacity(128);
+        push_clause!(query, "ALTER TABLE");
+
+        if self.data.if_exists {
+            push_clause!(query, "IF EXISTS");
+        }
+
+        let name = &self.data.name;
+        push_clause!(query, "{name}");
+
+        if let Some(ref schema) = self.data.schema {
+            push_clause!(query, "{schema}");
+        }
+
+        if let Some(ref changefeed) = self.data.changefeed {
+            push_clause!(query, "CHANGEFEED {changefeed}");
+        }
+
+        if let Some(ref permissions) = self.data.permissions {
+            push_clause!(query, "PERMISSIONS {permissions}");
+        }
+
+        if let Some(ref comment) = self.data.comment {
+            let comment = quote_str(comment);
+            push_clause!(query, "COMMENT {comment}");
+        }
+
+        query
+    }
+}
+
+impl IntoStatement for AlterTableBuilder {
+    fn into_statement(self) -> String {
+        self.build()
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{
     enums::ReturnClause,
     internal_macros::push_clause,
-    traits::IntoTimeout,
+    traits::{IntoStatement, IntoTarget, IntoTimeout},
     types::create::{ContentMode, CreateData, SetField},
 };
 use std::fmt::Write;
@@ -42,6 +42,9 @@ impl CreateBuilder {
     /// Multiple calls accumulate assignments. If a `CONTENT` clause was previously
     /// set, it is replaced by the `SET` clause.
     ///
+    /// `value` is a raw SurrealQL expression, a script [`Param`](crate::types::script::Param)
+    /// or a statement builder, which is rendered as a parenthesized subquery.
+    ///
     /// # Example
     /// ```
     /// # use surrealex::QueryBuilder;
@@ -51,18 +54,19 @@ impl CreateBuilder {
     ///     .build();
     /// assert_eq!(sql, "CREATE person SET name = 'Tobie', company = 'SurrealDB'");
     /// ```
-    pub fn set(mut self, field: &str, value: &str) -> Self {
+    pub fn set(mut self, field: &str, value: impl IntoTarget) -> Self {
+        let value = value.into_target();
         match &mut self.data.content {
             Some(ContentMode::Set(fields)) => {
                 fields.push(SetField {
                     field: field.to_string(),
-                    value: value.to_string(),
+                    value,
                 });
             }
             _ => {
                 self.data.content = Some(ContentMode::Set(vec![SetField {
                     field: field.to_string(),
-                    value: value.to_string(),
+                    value,
                 }]));
             }
         }
@@ -168,19 +172,7 @@ impl CreateBuilder {
         }
 
         if let Some(ref content) = self.data.content {
-            match content {
-                ContentMode::Content(value) => {
-                    push_clause!(query, "CONTENT {value}");
-                }
-                ContentMode::Set(fields) => {
-                    let assignments: String = fields
-                        .iter()
-                        .map(|f| format!("{} = {}", f.field, f.value))
-                        .collect::<Vec<String>>()
-                        .join(", ");
-                    push_clause!(query, "SET {assignments}");
-                }
-            }
+            push_clause!(query, "{content}");
         }
 
         if let Some(ref rc) = self.data.return_clause {
@@ -194,3 +186,15 @@ impl CreateBuilder {
         query
     }
 }
+
+impl IntoStatement for CreateBuilder {
+    fn into_statement(self) -> String {
+        self.build()
+    }
+}
+
+impl IntoTarget for CreateBuilder {
+    fn into_target(self) -> String {
+        format!("({})", self.build())
+    }
+}
@@ -0,0 +1,245 @@
+use crate::{
+    SurrealV1,
+    enums::Base,
+    traits::{IntoStatement, IntoTimeout},
+    types::define::{
+        DefineMode,
+        access::{AccessType, BearerSubject, DefineAccessData, JwtVerify, TokenBase},
+    },
+    versioning::{SinceV2, access::VersionedAccess},
+};
+
+/// Builder for DEFINE ACCESS statements, created via
+/// [`QueryBuilder::define_access`].
+///
+/// Pick the access type with one of the `type_*` methods (or start a record
+/// access with [`signup`](Self::signup) / [`signin`](Self::signin)) to get a
+/// buildable [`DefineAccessReady`]. An access method without a type can't be
+/// built:
+///
+/// ```compile_fail
+/// # use surrealex::QueryBuilder;
+/// QueryBuilder::define_access("api").build();
+/// ```
+///
+/// [`QueryBuilder::define_access`]: crate::QueryBuilder::define_access
+pub struct DefineAccessBuilder<V> {
+    pub data: DefineAccessData,
+    pub(crate) renderer: V,
+}
+
+impl<V: SinceV2> DefineAccessBuilder<V> {
+    /// Adds `OVERWRITE`, replacing the access definition if it already exists.
+    ///
+    /// This replaces any previous `IF NOT EXISTS` modifier.
+    pub fn overwrite(mut self) -> Self {
+        self.data.mode = Some(DefineMode::Overwrite);
+        self
+    }
+
+    /// Adds `IF NOT EXISTS`, leaving an existing access definition untouched.
+    ///
+    /// This replaces any previous `OVERWRITE` modifier.
+    pub fn if_not_exists(mut self) -> Self {
+        self.data.mode = Some(DefineMode::IfNotExists);
+        self
+    }
+
+    /// Sets the level the access method is defined on. Defaults to `DATABASE`.
+    pub fn on(mut self, base: Base) -> Self {
+        self.data.base = base;
+        self
+    }
+
+    /// Sets the access type to `TYPE BEARER FOR USER | RECORD`.
+    ///
+    /// Only available when targeting SurrealDB 2.0 or newer.
+    ///
+    /// ```compile_fail
+    /// use surrealex::{QueryBuilder, SurrealV1};
+    /// use surrealex::types::define::access::BearerSubject;
+    ///
+    /// QueryBuilder::with_version(SurrealV1)
+    ///     .define_access("api")
+    ///     .type_bearer(BearerSubject::User);
+    /// ```
+    pub fn type_bearer(mut self, subject: BearerSubject) -> DefineAccessReady<V> {
+        self.data.access_type = Some(AccessType::Bearer(subject));
+        self.transition_to_ready()
+    }
+}
+
+impl DefineAccessBuilder<SurrealV1> {
+    /// Sets the level a `DEFINE TOKEN` is defined on. Defaults to `DATABASE`.
+    ///
+    /// SurrealDB 1.x scopes are always defined on the database, so this only
+    /// affects JWT access. Tokens can't be defined on the root level:
+    ///
+    /// ```compile_fail
+    /// use surrealex::{QueryBuilder, SurrealV1};
+    /// use surrealex::enums::Base;
+    ///
+    /// QueryBuilder::with_version(SurrealV1)
+    ///     .define_access("token")
+    ///     .on(Base::Root);
+    /// ```
+    pub fn on(mut self, base: TokenBase) -> Self {
+        self.data.base = base.into();
+        self
+    }
+}
+
+impl<V: VersionedAccess> DefineAccessBuilder<V> {
+    /// Sets the access type to `TYPE RECORD`.
+    ///
+    /// On SurrealDB 1.x this renders a `DEFINE SCOPE` statement.
+    pub fn type_record(mut self) -> DefineAccessReady<V> {
+        self.data.access_type = Some(AccessType::record());
+        self.transition_to_ready()
+    }
+
+    /// Sets the access type to `TYPE JWT`.
+    ///
+    /// On SurrealDB 1.x this renders a `DEFINE TOKEN` statement.
+    pub fn type_jwt(mut self, verify: JwtVerify) -> DefineAccessReady<V> {
+        self.data.access_type = Some(AccessType::Jwt(verify));
+        self.transition_to_ready()
+    }
+
+    /// Starts a record access with the given `SIGNUP` statement.
+    ///
+    /// # Example
+    /// ```
+    /// # use surrealex::QueryBuilder;
+    /// let sql = QueryBuilder::define_access("account")
+    ///     .signup("CREATE user SET email = $email, pass = crypto::argon2::generate($pass)")
+    ///     .signin("SELECT * FROM user WHERE email = $email AND crypto::argon2::compare(pass, $pass)")
+    ///     .duration_session("24h")
+    ///     .build();
+    /// assert_eq!(
+    ///     sql,
+    ///     "DEFINE ACCESS account ON DATABASE TYPE RECORD SIGNUP (CREATE user SET email = $email, pass = crypto::argon2::generate($pass)) SIGNIN (SELECT * FROM user WHERE email = $email AND crypto::argon2::compare(pass, $pass)) DURATION FOR SESSION 24h"
+    /// );
+    /// ```
+    pub fn signup(self, statement: impl IntoStatement) -> DefineAccessReady<V> {
+        self.type_record().signup(statement)
+    }
+
+    /// Starts a record access with the given `SIGNIN` statement.
+    pub fn signin(self, statement: impl IntoStatement) -> DefineAccessReady<V> {
+        self.type_record().signin(statement)
+    }
+
+    /// Starts a record access with the given `WITH JWT` verification.
+    ///
+    /// On SurrealDB 1.x this renders an additional `DEFINE TOKEN ... ON SCOPE`
+    /// statement.
+    pub fn with_jwt(self, verify: JwtVerify) -> DefineAccessReady<V> {
+        self.type_record().with_jwt(verify)
+    }
+}
+
+impl<V> DefineAccessBuilder<V> {
+    fn transition_to_ready(self) -> DefineAccessReady<V> {
+        DefineAccessReady {
+            data: self.data,
+            renderer: self.renderer,
+        }
+    }
+}
+
+/// A DEFINE ACCESS statement with its access type set, ready to be built.
+pub struct DefineAccessReady<V> {
+    pub data: DefineAccessData,
+    pub(crate) renderer: V,
+}
+
+impl<V: SinceV2> DefineAccessReady<V> {
+    /// Sets the `FOR GRANT` duration.
+    pub fn duration_grant(mut self, duration: impl IntoTimeout) -> Self {
+        self.data.durations.grant = Some(duration.into_timeout());
+        self
+    }
+
+    /// Sets the `FOR TOKEN` duration.
+    pub fn duration_token(mut self, duration: impl IntoTimeout) -> Self {
+        self.data.durations.token = Some(duration.into_timeout());
+        self
+    }
+}
+
+impl<V: VersionedAccess> DefineAccessReady<V> {
+    /// Sets the access type to `TYPE JWT`, replacing the current access type.
+    pub fn type_jwt(mut self, verify: JwtVerify) -> Self {
+        self.data.access_type = Some(AccessType::Jwt(verify));
+        self
+    }
+
+    /// Sets the `SIGNUP` statement of a record access, switching to
+    /// `TYPE RECORD` if needed.
+    pub fn signup(mut self, statement: impl IntoStatement) -> Self {
+        if let AccessType::Record { signup, .. } = self.record() {
+            *signup = Some(statement.into_statement());
+        }
+        self
+    }
+
+    /// Sets the `SIGNIN` statement of a record access, switching to
+    /// `TYPE RECORD` if needed.
+    pub fn signin(mut self, statement: impl IntoStatement) -> Self {
+        if let AccessType::Record { signin, .. } = self.record() {
+            *signin = Some(statement.into_statement());
+        }
+        self
+    }
+
+    /// Sets the `WITH JWT` verification of a record access, switching to
+    /// `TYPE RECORD` if needed.
+    ///
+    /// On SurrealDB 1.x this renders an additional `DEFINE TOKEN ... ON SCOPE`
+    /// statement.
+    pub fn with_jwt(mut self, verify: JwtVerify) -> Self {
+        if let AccessType::Record { jwt, .. } = self.record() {
+            *jwt = Some(verify);
+        }
+        self
+    }
+
+    /// Sets the `FOR SESSION` duration.
+    pub fn duration_session(mut self, duration: impl IntoTimeout) -> Self {
+        self.data.durations.session = Some(duration.into_timeout());
+        self
+    }
+
+    /// Sets the `COMMENT` clause. The text is rendered as a quoted string.
+    pub fn comment(mut self, comment: &str) -> Self {
+        self.data.comment = Some(comment.to_string());
+        self
+    }
+
+    /// Builds the final DEFINE ACCESS query string using the configured
+    /// version's rendering rules.
+    pub fn build(self) -> String {
+        self.renderer.define_access(&self.data)
+    }
+
+    /// Returns the record access type, replacing any other access type.
+    fn record(&mut self) -> &mut AccessType {
+        let access_type = self.data.access_type.get_or_insert_with(AccessType::record);
+        match access_type {
+            AccessType::Record { .. } => {}
+            _ => *access_type = AccessType::record(),
+        }
+        access_type
+    }
+}
+
+impl<V: VersionedAccess> IntoStatement for DefineAccessReady<V> {
+    fn into_statement(self) -> String {
+        self.build()
+    }
+
+    fn is_multi_statement(&self) -> bool {
+        self.renderer.is_multi_statement(&self.data)
+    }
+}
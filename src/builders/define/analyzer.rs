@@ -0,0 +1,131 @@
+use std::{fmt::Write, marker::PhantomData};
+
+use crate::{
+    internal_macros::push_clause,
+    internal_utils::quote_str,
+    traits::IntoStatement,
+    types::define::{
+        DefineMode,
+        analyzer::{AnalyzerFilter, DefineAnalyzerData, Tokenizer},
+    },
+    versioning::SinceV2,
+};
+
+pub struct DefineAnalyzerBuilder<V> {
+    pub data: DefineAnalyzerData,
+    pub(crate) version: PhantomData<V>,
+}
+
+impl<V: SinceV2> DefineAnalyzerBuilder<V> {
+    /// Adds `OVERWRITE`, replacing the analyzer definition if it already exists.
+    ///
+    /// This replaces any previous `IF NOT EXISTS` modifier.
+    pub fn overwrite(mut self) -> Self {
+        self.data.mode = Some(DefineMode::Overwrite);
+        self
+    }
+
+    /// Adds `IF NOT EXISTS`, leaving an existing analyzer definition untouched.
+    ///
+    /// This replaces any previous `OVERWRITE` modifier.
+    pub fn if_not_exists(mut self) -> Self {
+        self.data.mode = Some(DefineMode::IfNotExists);
+        self
+    }
+}
+
+impl<V> DefineAnalyzerBuilder<V> {
+    /// Sets the `FUNCTION fn::...` applied to the text before it is tokenized.
+    pub fn function(mut self, name: &str) -> Self {
+        self.data.function = Some(name.to_string());
+        self
+    }
+
+    /// Appends tokenizers to the `TOKENIZERS` clause.
+    ///
+    /// # Example
+    /// ```
+    /// # use surrealex::QueryBuilder;
+    /// use surrealex::types::define::analyzer::{AnalyzerFilter, Language, Tokenizer};
+    ///
+    /// let sql = QueryBuilder::define_analyzer("english")
+    ///     .tokenizers(vec![Tokenizer::Blank, Tokenizer::Class])
+    ///     .filters(vec![
+    ///         AnalyzerFilter::Lowercase,
+    ///         AnalyzerFilter::snowball(Language::English),
+    ///     ])
+    ///     .build();
+    /// assert_eq!(
+    ///     sql,
+    ///     "DEFINE ANALYZER english TOKENIZERS blank,class FILTERS lowercase,snowball(english)"
+    /// );
+    /// ```
+    pub fn tokenizers(mut self, tokenizers: Vec<Tokenizer>) -> Self {
+        self.data.tokenizers.extend(tokenizers);
+        self
+    }
+
+    /// Appends filters to the `FILTERS` clause, applied in order.
+    pub fn filters(mut self, filters: Vec<AnalyzerFilter>) -> Self {
+        self.data.filters.extend(filters);
+        self
+    }
+
+    /// Sets the `COMMENT` clause. The text is rendered as a quoted string.
+    pub fn comment(mut self, comment: &str) -> Self {
+        self.data.comment = Some(comment.to_string());
+        self
+    }
+
+    /// Builds the final DEFINE ANALYZER query string.
+    pub fn build(self) -> String {
+        let mut query = String::with_capacity(128);
+        push_clause!(query, "DEFINE ANALYZER");
+
+        if let Some(ref mode) = self.data.mode {
+            push_clause!(query, "{mode}");
+        }
+
+        let name = &self.data.name;
+        push_clause!(query, "{name}");
+
+        if let Some(ref function) = self.data.function {
+            push_clause!(query, "FUNCTION {function}");
+        }
+
+        if !self.data.tokenizers.is_empty() {
+            let tokenizers = self
+                .data
+                .tokenizers
+                .iter()
+                .map(|tokenizer| tokenizer.to_string())
+                .collect::<Vec<String>>()
+                .join(",");
+            push_clause!(query, "TOKENIZERS {tokenizers}");
+        }
+
+        if !self.data.filters.is_empty() {
+            let filters = self
+                .data
+                .filters
+                .iter()
+                .map(|filter| filter.to_string())
+                .collect::<Vec<String>>()
+                .join(",");
+            push_clause!(query, "FILTERS {filters}");
+        }
+
+        if let Some(ref comment) = self.data.comment {
+            let comment = quote_str(comment);
+            push_clause!(query, "COMMENT {comment}");
+        }
+
+        query
+    }
+}
+
+impl<V> IntoStatement for DefineAnalyzerBuilder<V> {
+    fn into_statement(self) -> String {
+        self.build()
+    }
+}
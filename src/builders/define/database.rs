@@ -0,0 +1,86 @@
+use std::{fmt::Write, marker::PhantomData};
+
+use crate::{
+    internal_macros::push_clause,
+    internal_utils::quote_str,
+    traits::{IntoStatement, IntoTimeout},
+    types::define::{Changefeed, DefineMode, database::DefineDatabaseData},
+    versioning::SinceV2,
+};
+
+pub struct DefineDatabaseBuilder<V> {
+    pub data: DefineDatabaseData,
+    pub(crate) version: PhantomData<V>,
+}
+
+impl<V: SinceV2> DefineDatabaseBuilder<V> {
+    /// Adds `OVERWRITE`, replacing the database definition if it already exists.
+    ///
+    /// This replaces any previous `IF NOT EXISTS` modifier.
+    pub fn overwrite(mut self) -> Self {
+        self.data.mode = Some(DefineMode::Overwrite);
+        self
+    }
+
+    /// Adds `IF NOT EXISTS`, leaving an existing database definition untouched.
+    ///
+    /// This replaces any previous `OVERWRITE` modifier.
+    pub fn if_not_exists(mut self) -> Self {
+        self.data.mode = Some(DefineMode::IfNotExists);
+        self
+    }
+}
+
+impl<V> DefineDatabaseBuilder<V> {
+    /// Sets the `COMMENT` clause. The text is rendered as a quoted string.
+    pub fn comment(mut self, comment: &str) -> Self {
+        self.data.comment = Some(comment.to_string());
+        self
+    }
+
+    /// Sets the `CHANGEFEED` retention duration for every table in the database.
+    ///
+    /// # Example
+    /// ```
+    /// # use surrealex::QueryBuilder;
+    /// let sql = QueryBuilder::define_database("app").changefeed("7d").build();
+    /// assert_eq!(sql, "DEFINE DATABASE app CHANGEFEED 7d");
+    /// ```
+    pub fn changefeed(mut self, duration: impl IntoTimeout) -> Self {
+        self.data.changefeed = Some(Changefeed {
+            duration: duration.into_timeout(),
+            include_original: false,
+        });
+        self
+    }
+
+    /// Builds the final DEFINE DATABASE query string.
+    pub fn build(self) -> String {
+        let mut query = String::with_capacity(64);
+        push_clause!(query, "DEFINE DATABASE");
+
+        if let Some(ref mode) = self.data.mode {
+            push_clause!(query, "{mode}");
+        }
+
+        let name = &self.data.name;
+        push_clause!(query, "{name}");
+
+        if let Some(ref comment) = self.data.comment {
+            let comment = quote_str(comment);
+            push_clause!(query, "COMMENT {comment}");
+        }
+
+        if let Some(ref changefeed) = self.data.changefeed {
+            push_clause!(query, "CHANGEFEED {changefeed}");
+        }
+
+        query
+    }
+}
+
+impl<V> IntoStatement for DefineDatabaseBuilder<V> {
+    fn into_statement(self) -> String {
+        self.build()
+    }
+}
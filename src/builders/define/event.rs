@@ -0,0 +1,123 @@
+use std::{fmt::Write, marker::PhantomData};
+
+use crate::{
+    enums::Condition,
+    internal_macros::push_clause,
+    internal_utils::quote_str,
+    traits::IntoStatement,
+    types::define::{DefineMode, event::DefineEventData},
+    versioning::SinceV2,
+};
+
+pub struct DefineEventBuilder<V> {
+    pub data: DefineEventData,
+    pub(crate) version: PhantomData<V>,
+}
+
+impl<V: SinceV2> DefineEventBuilder<V> {
+    /// Adds `OVERWRITE`, replacing the event definition if it already exists.
+    ///
+    /// This replaces any previous `IF NOT EXISTS` modifier.
+    pub fn overwrite(mut self) -> Self {
+        self.data.mode = Some(DefineMode::Overwrite);
+        self
+    }
+
+    /// Adds `IF NOT EXISTS`, leaving an existing event definition untouched.
+    ///
+    /// This replaces any previous `OVERWRITE` modifier.
+    pub fn if_not_exists(mut self) -> Self {
+        self.data.mode = Some(DefineMode::IfNotExists);
+        self
+    }
+}
+
+impl<V> DefineEventBuilder<V> {
+    /// Appends a `WHEN` condition. Multiple calls are joined with `AND`.
+    ///
+    /// Accepts an [`EventType`](crate::types::define::event::EventType), which
+    /// renders the matching `$event = "..."` check.
+    pub fn when<T: Into<Condition>>(mut self, condition: T) -> Self {
+        self.data.when.push(condition.into());
+        self
+    }
+
+    /// Appends a statement to the `THEN { ... }` block.
+    ///
+    /// Multiple calls accumulate statements, separated by `;`.
+    ///
+    /// # Example
+    /// ```
+    /// # use surrealex::QueryBuilder;
+    /// use surrealex::types::define::event::{self, EventType};
+    ///
+    /// let sql = QueryBuilder::define_event("email", "user")
+    ///     .when(EventType::Update)
+    ///     .when(event::changed("email"))
+    ///     .then(
+    ///         QueryBuilder::create("log")
+    ///             .set("user", "$value.id")
+    ///             .set("from", &event::before("email"))
+    ///             .set("to", &event::after("email")),
+    ///     )
+    ///     .build();
+    /// assert_eq!(
+    ///     sql,
+    ///     "DEFINE EVENT email ON TABLE user WHEN $event = \"UPDATE\" AND $before.email != $after.email THEN { CREATE log SET user = $value.id, from = $before.email, to = $after.email }"
+    /// );
+    /// ```
+    pub fn then(mut self, statement: impl IntoStatement) -> Self {
+        self.data.then.push(statement.into_statement());
+        self
+    }
+
+    /// Sets the `COMMENT` clause. The text is rendered as a quoted string.
+    pub fn comment(mut self, comment: &str) -> Self {
+        self.data.comment = Some(comment.to_string());
+        self
+    }
+
+    /// Builds the final DEFINE EVENT query string.
+    pub fn build(self) -> String {
+        let mut query = String::with_capacity(128);
+        push_clause!(query, "DEFINE EVENT");
+
+        if let Some(ref mode) = self.data.mode {
+            push_clause!(query, "{mode}");
+        }
+
+        let name = &self.data.name;
+        let table = &self.data.table;
+        push_clause!(query, "{name} ON TABLE {table}");
+
+        if !self.data.when.is_empty() {
+            let conditions: String = self
+                .data
+                .when
+                .iter()
+                .map(|cond| cond.to_string())
+                .collect::<Vec<String>>()
+                .join(" AND ");
+
+            push_clause!(query, "WHEN {conditions}");
+        }
+
+        if !self.data.then.is_empty() {
+            let statements = self.data.then.join("; ");
+            push_clause!(query, "THEN {{ {statements} }}");
+        }
+
+        if let Some(ref comment) = self.data.comment {
+            let comment = quote_str(comment);
+            push_clause!(query, "COMMENT {comment}");
+        }
+
+        query
+    }
+}
+
+impl<V> IntoStatement for DefineEventBuilder<V> {
+    fn into_statement(self) -> String {
+        self.build()
+    }
+}
@@ -0,0 +1,231 @@
+use std::{fmt::Write, marker::PhantomData};
+
+use crate::{
+    enums::Condition,
+    internal_macros::push_clause,
+    internal_utils::quote_str,
+    traits::IntoStatement,
+    types::define::{
+        DefineMode, Permission, PermissionRule, Permissions,
+        field::{DefineFieldData, FieldDefault, FieldPermissionKind},
+        kind::Kind,
+    },
+    versioning::SinceV2,
+};
+
+pub struct DefineFieldBuilder<V> {
+    pub data: DefineFieldData,
+    pub(crate) version: PhantomData<V>,
+}
+
+impl<V: SinceV2> DefineFieldBuilder<V> {
+    /// Adds `OVERWRITE`, replacing the field definition if it already exists.
+    ///
+    /// This replaces any previous `IF NOT EXISTS` modifier.
+    pub fn overwrite(mut self) -> Self {
+        self.data.mode = Some(DefineMode::Overwrite);
+        self
+    }
+
+    /// Adds `IF NOT EXISTS`, leaving an existing field definition untouched.
+    ///
+    /// This replaces any previous `OVERWRITE` modifier.
+    pub fn if_not_exists(mut self) -> Self {
+        self.data.mode = Some(DefineMode::IfNotExists);
+        self
+    }
+}
+
+impl<V> DefineFieldBuilder<V> {
+    /// Adds `FLEXIBLE`, allowing schemaless nested values in object fields.
+    pub fn flexible(mut self) -> Self {
+        self.data.flexible = true;
+        self
+    }
+
+    /// Sets the `TYPE` clause.
+    ///
+    /// # Example
+    /// ```
+    /// # use surrealex::QueryBuilder;
+    /// use surrealex::types::define::kind::Kind;
+    ///
+    /// let sql = QueryBuilder::define_field("tags", "post")
+    ///     .r#type(Kind::array_max(Kind::String, 10))
+    ///     .build();
+    /// assert_eq!(sql, "DEFINE FIELD tags ON TABLE post TYPE array<string, 10>");
+    /// ```
+    pub fn r#type(mut self, kind: Kind) -> Self {
+        self.data.kind = Some(kind);
+        self
+    }
+
+    /// Sets the `DEFAULT @value` clause.
+    ///
+    /// This replaces any previous `DEFAULT ALWAYS` clause.
+    pub fn default(mut self, value: &str) -> Self {
+        self.data.default = Some(FieldDefault {
+            value: value.to_string(),
+            always: false,
+        });
+        self
+    }
+
+    /// Sets the `DEFAULT ALWAYS @value` clause.
+    ///
+    /// This replaces any previous `DEFAULT` clause.
+    pub fn default_always(mut self, value: &str) -> Self {
+        self.data.default = Some(FieldDefault {
+            value: value.to_string(),
+            always: true,
+        });
+        self
+    }
+
+    /// Adds `READONLY`, preventing the field from being changed after creation.
+    pub fn readonly(mut self) -> Self {
+        self.data.readonly = true;
+        self
+    }
+
+    /// Sets the `VALUE @expression` clause.
+    pub fn value(mut self, expression: &str) -> Self {
+        self.data.value = Some(expression.to_string());
+        self
+    }
+
+    /// Appends an `ASSERT` condition. Multiple calls are joined with `AND`.
+    ///
+    /// # Example
+    /// ```
+    /// # use surrealex::QueryBuilder;
+    /// use surrealex::types::define::kind::Kind;
+    ///
+    /// let sql = QueryBuilder::define_field("email", "user")
+    ///     .r#type(Kind::String)
+    ///     .assert("string::is::email($value)")
+    ///     .build();
+    /// assert_eq!(
+    ///     sql,
+    ///     "DEFINE FIELD email ON TABLE user TYPE string ASSERT string::is::email($value)"
+    /// );
+    /// ```
+    pub fn assert<T: Into<Condition>>(mut self, condition: T) -> Self {
+        self.data.assert_clause.push(condition.into());
+        self
+    }
+
+    /// Sets the PERMISSIONS clause to `PERMISSIONS NONE`.
+    pub fn permissions_none(mut self) -> Self {
+        self.data.permissions = Some(Permissions::None);
+        self
+    }
+
+    /// Sets the PERMISSIONS clause to `PERMISSIONS FULL`.
+    pub fn permissions_full(mut self) -> Self {
+        self.data.permissions = Some(Permissions::Full);
+        self
+    }
+
+    /// Adds a `FOR @kinds @permission` rule to the PERMISSIONS clause.
+    ///
+    /// Multiple calls accumulate rules. A previous `NONE` or `FULL` clause is
+    /// replaced. Fields only support `select`, `create` and `update` rules:
+    ///
+    /// ```compile_fail
+    /// # use surrealex::QueryBuilder;
+    /// use surrealex::types::define::{Permission, PermissionKind};
+    ///
+    /// QueryBuilder::define_field("email", "user")
+    ///     .permissions_for(vec![PermissionKind::Delete], Permission::None);
+    /// ```
+    pub fn permissions_for(
+        mut self,
+        kinds: Vec<FieldPermissionKind>,
+        permission: impl Into<Permission>,
+    ) -> Self {
+        let rule = PermissionRule {
+            kinds: kinds.into_iter().map(Into::into).collect(),
+            permission: permission.into(),
+        };
+        match &mut self.data.permissions {
+            Some(Permissions::For(rules)) => rules.push(rule),
+            _ => self.data.permissions = Some(Permissions::For(vec![rule])),
+        }
+        self
+    }
+
+    /// Sets the `COMMENT` clause. The text is rendered as a quoted string.
+    pub fn comment(mut self, comment: &str) -> Self {
+        self.data.comment = Some(comment.to_string());
+        self
+    }
+
+    /// Builds the final DEFINE FIELD query string.
+    pub fn build(self) -> String {
+        let mut query = String::with_capacity(128);
+        push_clause!(query, "DEFINE FIELD");
+
+        if let Some(ref mode) = self.data.mode {
+            push_clause!(query, "{mode}");
+        }
+
+        let name = &self.data.name;
+        let table = &self.data.table;
+        push_clause!(query, "{name} ON TABLE {table}");
+
+        if self.data.flexible {
+            push_clause!(query, "FLEXIBLE");
+        }
+
+        if let Some(ref kind) = self.data.kind {
+            push_clause!(query, "TYPE {kind}");
+        }
+
+        if let Some(ref default) = self.data.default {
+            let value = &default.value;
+            if default.always {
+                push_clause!(query, "DEFAULT ALWAYS {value}");
+            } else {
+                push_clause!(query, "DEFAULT {value}");
+            }
+        }
+
+        if self.data.readonly {
+            push_clause!(query, "READONLY");
+        }
+
+        if let Some(ref value) = self.data.value {
+            push_clause!(query, "VALUE {value}");
+        }
+
+        if !self.data.assert_clause.is_empty() {
+            let conditions: String = self
+                .data
+                .assert_clause
+                .iter()
+                .map(|cond| cond.to_string())
+                .collect::<Vec<String>>()
+                .join(" AND ");
+
+            push_clause!(query, "ASSERT {conditions}");
+        }
+
+        if let Some(ref permissions) = self.data.permissions {
+            push_clause!(query, "PERMISSIONS {permissions}");
+        }
+
+        if let Some(ref comment) = self.data.comment {
+            let comment = quote_str(comment);
+            push_clause!(query, "COMMENT {comment}");
+        }
+
+        query
+    }
+}
+
+impl<V> IntoStatement for DefineFieldBuilder<V> {
+    fn into_statement(self) -> String {
+        self.build()
+    }
+}
@@ -0,0 +1,191 @@
+use std::{fmt::Write, marker::PhantomData};
+
+use crate::{
+    enums::Condition,
+    internal_macros::push_clause,
+    internal_utils::quote_str,
+    traits::{IntoStatement, IntoTarget},
+    types::define::{
+        DefineMode, Permission,
+        function::{DefineFunctionData, FunctionArg},
+        kind::Kind,
+    },
+    versioning::SinceV2,
+};
+
+pub struct DefineFunctionBuilder<V> {
+    pub data: DefineFunctionData,
+    pub(crate) version: PhantomData<V>,
+}
+
+impl<V: SinceV2> DefineFunctionBuilder<V> {
+    /// Adds `OVERWRITE`, replacing the function definition if it already exists.
+    ///
+    /// This replaces any previous `IF NOT EXISTS` modifier.
+    pub fn overwrite(mut self) -> Self {
+        self.data.mode = Some(DefineMode::Overwrite);
+        self
+    }
+
+    /// Adds `IF NOT EXISTS`, leaving an existing function definition untouched.
+    ///
+    /// This replaces any previous `OVERWRITE` modifier.
+    pub fn if_not_exists(mut self) -> Self {
+        self.data.mode = Some(DefineMode::IfNotExists);
+        self
+    }
+
+    /// Sets the return type, rendered as `-> @kind` after the argument list.
+    ///
+    /// Only available when targeting SurrealDB 2.0 or newer.
+    pub fn returns(mut self, kind: Kind) -> Self {
+        self.data.returns = Some(kind);
+        self
+    }
+}
+
+impl<V> DefineFunctionBuilder<V> {
+    /// Appends a typed argument, rendered as `$name: kind`.
+    ///
+    /// A leading `$` in `name` is optional.
+    ///
+    /// # Example
+    /// ```
+    /// # use surrealex::QueryBuilder;
+    /// use surrealex::types::define::kind::Kind;
+    ///
+    /// let sql = QueryBuilder::define_function("fn::greet")
+    ///     .arg("name", Kind::String)
+    ///     .returns(Kind::String)
+    ///     .r#return("'Hello, ' + $name + '!'")
+    ///     .build();
+    /// assert_eq!(
+    ///     sql,
+    ///     "DEFINE FUNCTION fn::greet($name: string) -> string { RETURN 'Hello, ' + $name + '!' }"
+    /// );
+    /// ```
+    pub fn arg(mut self, name: &str, kind: Kind) -> Self {
+        self.data.args.push(FunctionArg {
+            name: name.trim_start_matches('$').to_string(),
+            kind,
+        });
+        self
+    }
+
+    /// Appends a statement to the function body.
+    pub fn statement(mut self, statement: impl IntoStatement) -> Self {
+        self.data.body.push(statement.into_statement());
+        self
+    }
+
+    /// Appends a `LET $name = @value` statement to the function body.
+    ///
+    /// A leading `$` in `name` is optional. Statement builders are rendered as
+    /// parenthesized subqueries.
+    ///
+    /// # Example
+    /// ```
+    /// # use surrealex::QueryBuilder;
+    /// use surrealex::types::define::kind::Kind;
+    ///
+    /// let sql = QueryBuilder::define_function("fn::count_posts")
+    ///     .arg("user", Kind::record(vec!["user"]))
+    ///     .r#let(
+    ///         "posts",
+    ///         QueryBuilder::select(surrealex::fields!("id"))
+    ///             .from("post")
+    ///             .r#where("author = $user"),
+    ///     )
+    ///     .r#return("array::len($posts)")
+    ///     .build();
+    /// assert_eq!(
+    ///     sql,
+    ///     "DEFINE FUNCTION fn::count_posts($user: record<user>) { LET $posts = (SELECT id FROM post WHERE author = $user); RETURN array::len($posts) }"
+    /// );
+    /// ```
+    pub fn r#let(mut self, name: &str, value: impl IntoTarget) -> Self {
+        let name = name.trim_start_matches('$');
+        let value = value.into_target();
+        self.data.body.push(format!("LET ${name} = {value}"));
+        self
+    }
+
+    /// Appends a `RETURN @value` statement to the function body.
+    pub fn r#return(mut self, value: impl IntoTarget) -> Self {
+        let value = value.into_target();
+        self.data.body.push(format!("RETURN {value}"));
+        self
+    }
+
+    /// Sets the `COMMENT` clause. The text is rendered as a quoted string.
+    pub fn comment(mut self, comment: &str) -> Self {
+        self.data.comment = Some(comment.to_string());
+        self
+    }
+
+    /// Sets the PERMISSIONS clause to `PERMISSIONS NONE`.
+    pub fn permissions_none(mut self) -> Self {
+        self.data.permissions = Some(Permission::None);
+        self
+    }
+
+    /// Sets the PERMISSIONS clause to `PERMISSIONS FULL`.
+    pub fn permissions_full(mut self) -> Self {
+        self.data.permissions = Some(Permission::Full);
+        self
+    }
+
+    /// Sets the PERMISSIONS clause to `PERMISSIONS WHERE @condition`.
+    pub fn permissions_where<T: Into<Condition>>(mut self, condition: T) -> Self {
+        self.data.permissions = Some(Permission::Where(condition.into()));
+        self
+    }
+
+    /// Builds the final DEFINE FUNCTION query string.
+    pub fn build(self) -> String {
+        let mut query = String::with_capacity(128);
+        push_clause!(query, "DEFINE FUNCTION");
+
+        if let Some(ref mode) = self.data.mode {
+            push_clause!(query, "{mode}");
+        }
+
+        let name = &self.data.name;
+        let args = self
+            .data
+            .args
+            .iter()
+            .map(|arg| arg.to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+        push_clause!(query, "{name}({args})");
+
+        if let Some(ref kind) = self.data.returns {
+            push_clause!(query, "-> {kind}");
+        }
+
+        if self.data.body.is_empty() {
+            push_clause!(query, "{{}}");
+        } else {
+            let body = self.data.body.join("; ");
+            push_clause!(query, "{{ {body} }}");
+        }
+
+        if let Some(ref comment) = self.data.comment {
+            let comment = quote_str(comment);
+            push_clause!(query, "COMMENT {comment}");
+        }
+
+        if let Some(ref permissions) = self.data.permissions {
+            push_clause!(query, "PERMISSIONS {permissions}");
+        }
+
+        query
+    }
+}
+
+impl<V> IntoStatement for DefineFunctionBuilder<V> {
+    fn into_statement(self) -> String {
+        self.build()
+    }
+}
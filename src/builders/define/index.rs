@@ -0,0 +1,179 @@
+use std::{fmt::Write, marker::PhantomData};
+
+use crate::{
+    internal_macros::push_clause,
+    internal_utils::quote_str,
+    traits::IntoStatement,
+    types::define::{
+        DefineMode,
+        index::{DefineIndexData, HnswParams, IndexKind, MtreeParams, SearchParams},
+    },
+    versioning::SinceV2,
+};
+
+pub struct DefineIndexBuilder<V> {
+    pub data: DefineIndexData,
+    pub(crate) version: PhantomData<V>,
+}
+
+impl<V: SinceV2> DefineIndexBuilder<V> {
+    /// Adds `OVERWRITE`, replacing the index definition if it already exists.
+    ///
+    /// This replaces any previous `IF NOT EXISTS` modifier.
+    pub fn overwrite(mut self) -> Self {
+        self.data.mode = Some(DefineMode::Overwrite);
+        self
+    }
+
+    /// Adds `IF NOT EXISTS`, leaving an existing index definition untouched.
+    ///
+    /// This replaces any previous `OVERWRITE` modifier.
+    pub fn if_not_exists(mut self) -> Self {
+        self.data.mode = Some(DefineMode::IfNotExists);
+        self
+    }
+
+    /// Adds `CONCURRENTLY`, building the index in the background.
+    pub fn concurrently(mut self) -> Self {
+        self.data.concurrently = true;
+        self
+    }
+}
+
+impl<V> DefineIndexBuilder<V> {
+    /// Sets the indexed fields, rendered as `FIELDS @fields`.
+    ///
+    /// This replaces any previously set fields or columns.
+    pub fn fields<S: Into<String>>(mut self, fields: Vec<S>) -> Self {
+        self.data.fields = fields.into_iter().map(|s| s.into()).collect();
+        self.data.columns = false;
+        self
+    }
+
+    /// Sets the indexed fields, rendered as `COLUMNS @fields`.
+    ///
+    /// This replaces any previously set fields or columns.
+    pub fn columns<S: Into<String>>(mut self, columns: Vec<S>) -> Self {
+        self.data.fields = columns.into_iter().map(|s| s.into()).collect();
+        self.data.columns = true;
+        self
+    }
+
+    /// Makes the index a `UNIQUE` index.
+    ///
+    /// # Example
+    /// ```
+    /// # use surrealex::QueryBuilder;
+    /// let sql = QueryBuilder::define_index("userEmailIndex", "user")
+    ///     .columns(vec!["email"])
+    ///     .unique()
+    ///     .build();
+    /// assert_eq!(
+    ///     sql,
+    ///     "DEFINE INDEX userEmailIndex ON TABLE user COLUMNS email UNIQUE"
+    /// );
+    /// ```
+    pub fn unique(mut self) -> Self {
+        self.data.kind = Some(IndexKind::Unique);
+        self
+    }
+
+    /// Makes the index a full-text `SEARCH ANALYZER` index.
+    ///
+    /// # Example
+    /// ```
+    /// # use surrealex::QueryBuilder;
+    /// use surrealex::types::define::index::SearchParams;
+    ///
+    /// let sql = QueryBuilder::define_index("userNameIndex", "user")
+    ///     .fields(vec!["name"])
+    ///     .search(SearchParams::new("ascii").bm25_params(1.2, 0.75).highlights())
+    ///     .build();
+    /// assert_eq!(
+    ///     sql,
+    ///     "DEFINE INDEX userNameIndex ON TABLE user FIELDS name SEARCH ANALYZER ascii BM25(1.2,0.75) HIGHLIGHTS"
+    /// );
+    /// ```
+    pub fn search(mut self, params: SearchParams) -> Self {
+        self.data.kind = Some(IndexKind::Search(params));
+        self
+    }
+
+    /// Makes the index an `MTREE` vector index.
+    pub fn mtree(mut self, params: MtreeParams) -> Self {
+        self.data.kind = Some(IndexKind::Mtree(params));
+        self
+    }
+
+    /// Makes the index an `HNSW` vector index.
+    ///
+    /// # Example
+    /// ```
+    /// # use surrealex::QueryBuilder;
+    /// use surrealex::types::define::index::{Distance, HnswParams};
+    ///
+    /// let sql = QueryBuilder::define_index("pts_idx", "pts")
+    ///     .fields(vec!["point"])
+    ///     .hnsw(HnswParams::new(4).dist(Distance::Cosine).efc(150).m(12))
+    ///     .build();
+    /// assert_eq!(
+    ///     sql,
+    ///     "DEFINE INDEX pts_idx ON TABLE pts FIELDS point HNSW DIMENSION 4 DIST COSINE EFC 150 M 12"
+    /// );
+    /// ```
+    pub fn hnsw(mut self, params: HnswParams) -> Self {
+        self.data.kind = Some(IndexKind::Hnsw(params));
+        self
+    }
+
+    /// Sets the `COMMENT` clause. The text is rendered as a quoted string.
+    pub fn comment(mut self, comment: &str) -> Self {
+        self.data.comment = Some(comment.to_string());
+        self
+    }
+
+    /// Builds the final DEFINE INDEX query string.
+    pub fn build(self) -> String {
+        let mut query = String::with_capacity(128);
+        push_clause!(query, "DEFINE INDEX");
+
+        if let Some(ref mode) = self.data.mode {
+            push_clause!(query, "{mode}");
+        }
+
+        let name = &self.data.name;
+        let table = &self.data.table;
+        push_clause!(query, "{name} ON TABLE {table}");
+
+        if !self.data.fields.is_empty() {
+            let keyword = if self.data.columns {
+                "COLUMNS"
+            } else {
+                "FIELDS"
+            };
+            let fields = self.data.fields.join(", ");
+            push_clause!(query, "{keyword} {fields}");
+        }
+
+        if let Some(ref kind) = self.data.kind {
+            push_clause!(query, "{kind}");
+        }
+
+        if let Some(ref comment) = self.data.comment {
+            let comment = quote_str(comment);
+            push_clause!(query, "COMMENT {comment}");
+        }
+
+        if self.data.concurrently {
+            push_clause!(query, "CONCURRENTLY");
+        }
+
+        query
+    }
+}
+
+impl<V> IntoStatement for DefineIndexBuilder<V> {
+    fn into_statement(self) -> String {
+        self.build()
+    }
+}
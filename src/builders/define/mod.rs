@@ -0,0 +1,11 @@
+pub mod access;
+pub mod analyzer;
+pub mod database;
+pub mod event;
+pub mod field;
+pub mod function;
+pub mod index;
+pub mod namespace;
+pub mod param;
+pub mod table;
+pub mod user;
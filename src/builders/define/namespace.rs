@@ -0,0 +1,66 @@
+use std::{fmt::Write, marker::PhantomData};
+
+use crate::{
+    internal_macros::push_clause,
+    internal_utils::quote_str,
+    traits::IntoStatement,
+    types::define::{DefineMode, namespace::DefineNamespaceData},
+    versioning::SinceV2,
+};
+
+pub struct DefineNamespaceBuilder<V> {
+    pub data: DefineNamespaceData,
+    pub(crate) version: PhantomData<V>,
+}
+
+impl<V: SinceV2> DefineNamespaceBuilder<V> {
+    /// Adds `OVERWRITE`, replacing the namespace definition if it already exists.
+    ///
+    /// This replaces any previous `IF NOT EXISTS` modifier.
+    pub fn overwrite(mut self) -> Self {
+        self.data.mode = Some(DefineMode::Overwrite);
+        self
+    }
+
+    /// Adds `IF NOT EXISTS`, leaving an existing namespace definition untouched.
+    ///
+    /// This replaces any previous `OVERWRITE` modifier.
+    pub fn if_not_exists(mut self) -> Self {
+        self.data.mode = Some(DefineMode::IfNotExists);
+        self
+    }
+}
+
+impl<V> DefineNamespaceBuilder<V> {
+    /// Sets the `COMMENT` clause. The text is rendered as a quoted string.
+    pub fn comment(mut self, comment: &str) -> Self {
+        self.data.comment = Some(comment.to_string());
+        self
+    }
+
+    /// Builds the final DEFINE NAMESPACE query string.
+    pub fn build(self) -> String {
+        let mut query = String::with_capacity(64);
+        push_clause!(query, "DEFINE NAMESPACE");
+
+        if let Some(ref mode) = self.data.mode {
+            push_clause!(query, "{mode}");
+        }
+
+        let name = &self.data.name;
+        push_clause!(query, "{name}");
+
+        if let Some(ref comment) = self.data.comment {
+            let comment = quote_str(comment);
+            push_clause!(query, "COMMENT {comment}");
+        }
+
+        query
+    }
+}
+
+impl<V> IntoStatement for DefineNamespaceBuilder<V> {
+    fn into_statement(self) -> String {
+        self.build()
+    }
+}
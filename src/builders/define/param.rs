@@ -0,0 +1,94 @@
+use std::{fmt::Write, marker::PhantomData};
+
+use crate::{
+    enums::Condition,
+    internal_macros::push_clause,
+    internal_utils::quote_str,
+    traits::IntoStatement,
+    types::define::{DefineMode, Permission, param::DefineParamData},
+    versioning::SinceV2,
+};
+
+/// Builder for DEFINE PARAM statements, created via
+/// [`QueryBuilder::define_param`].
+///
+/// [`QueryBuilder::define_param`]: crate::QueryBuilder::define_param
+pub struct DefineParamBuilder<V> {
+    pub data: DefineParamData,
+    pub(crate) version: PhantomData<V>,
+}
+
+impl<V: SinceV2> DefineParamBuilder<V> {
+    /// Adds `OVERWRITE`, replacing the parameter definition if it already exists.
+    ///
+    /// This replaces any previous `IF NOT EXISTS` modifier.
+    pub fn overwrite(mut self) -> Self {
+        self.data.mode = Some(DefineMode::Overwrite);
+        self
+    }
+
+    /// Adds `IF NOT EXISTS`, leaving an existing parameter definition untouched.
+    ///
+    /// This replaces any previous `OVERWRITE` modifier.
+    pub fn if_not_exists(mut self) -> Self {
+        self.data.mode = Some(DefineMode::IfNotExists);
+        self
+    }
+}
+
+impl<V> DefineParamBuilder<V> {
+    /// Sets the `COMMENT` clause. The text is rendered as a quoted string.
+    pub fn comment(mut self, comment: &str) -> Self {
+        self.data.comment = Some(comment.to_string());
+        self
+    }
+
+    /// Sets the PERMISSIONS clause to `PERMISSIONS NONE`.
+    pub fn permissions_none(mut self) -> Self {
+        self.data.permissions = Some(Permission::None);
+        self
+    }
+
+    /// Sets the PERMISSIONS clause to `PERMISSIONS FULL`.
+    pub fn permissions_full(mut self) -> Self {
+        self.data.permissions = Some(Permission::Full);
+        self
+    }
+
+    /// Sets the PERMISSIONS clause to `PERMISSIONS WHERE @condition`.
+    pub fn permissions_where<T: Into<Condition>>(mut self, condition: T) -> Self {
+        self.data.permissions = Some(Permission::Where(condition.into()));
+        self
+    }
+
+    /// Builds the final DEFINE PARAM query string.
+    pub fn build(self) -> String {
+        let mut query = String::with_capacity(64);
+        push_clause!(query, "DEFINE PARAM");
+
+        if let Some(ref mode) = self.data.mode {
+            push_clause!(query, "{mode}");
+        }
+
+        let name = &self.data.name;
+        let value = &self.data.value;
+        push_clause!(query, "${name} VALUE {value}");
+
+        if let Some(ref comment) = self.data.comment {
+            let comment = quote_str(comment);
+            push_clause!(query, "COMMENT {comment}");
+        }
+
+        if let Some(ref permissions) = self.data.permissions {
+            push_clause!(query, "PERMISSIONS {permissions}");
+        }
+
+        query
+    }
+}
+
+impl<V> IntoStatement for DefineParamBuilder<V> {
+    fn into_statement(self) -> String {
+        self.build()
+    }
+}
@@ -0,0 +1,262 @@
+use std::{fmt::Write, marker::PhantomData};
+
+use crate::{
+    builders::select::FromReady,
+    internal_macros::push_clause,
+    internal_utils::quote_str,
+    traits::{IntoStatement, IntoTimeout},
+    types::define::{
+        Changefeed, DefineMode, Permission, PermissionKind, PermissionRule, Permissions,
+        table::{DefineTableData, SchemaMode, TableType},
+    },
+    versioning::SinceV2,
+};
+
+pub struct DefineTableBuilder<V> {
+    pub data: DefineTableData,
+    pub(crate) version: PhantomData<V>,
+}
+
+impl<V: SinceV2> DefineTableBuilder<V> {
+    /// Adds `OVERWRITE`, replacing the table definition if it already exists.
+    ///
+    /// This replaces any previous `IF NOT EXISTS` modifier. Only available when
+    /// targeting SurrealDB 2.0 or newer:
+    ///
+    /// ```compile_fail
+    /// # use surrealex::{QueryBuilder, SurrealV1};
+    /// let sql = QueryBuilder::with_version(SurrealV1)
+    ///     .define_table("person")
+    ///     .overwrite()
+    ///     .build();
+    /// ```
+    pub fn overwrite(mut self) -> Self {
+        self.data.mode = Some(DefineMode::Overwrite);
+        self
+    }
+
+    /// Adds `IF NOT EXISTS`, leaving an existing table definition untouched.
+    ///
+    /// This replaces any previous `OVERWRITE` modifier.
+    pub fn if_not_exists(mut self) -> Self {
+        self.data.mode = Some(DefineMode::IfNotExists);
+        self
+    }
+}
+
+impl<V> DefineTableBuilder<V> {
+    /// Adds `DROP`, turning the table into a write-only table that discards records.
+    pub fn drop(mut self) -> Self {
+        self.data.drop = true;
+        self
+    }
+
+    /// Marks the table as `SCHEMAFULL`.
+    pub fn schemafull(mut self) -> Self {
+        self.data.schema = Some(SchemaMode::Schemafull);
+        self
+    }
+
+    /// Marks the table as `SCHEMALESS`.
+    pub fn schemaless(mut self) -> Self {
+        self.data.schema = Some(SchemaMode::Schemaless);
+        self
+    }
+
+    /// Sets the table type to `TYPE ANY`.
+    pub fn type_any(mut self) -> Self {
+        self.data.table_type = Some(TableType::Any);
+        self
+    }
+
+    /// Sets the table type to `TYPE NORMAL`.
+    pub fn type_normal(mut self) -> Self {
+        self.data.table_type = Some(TableType::Normal);
+        self
+    }
+
+    /// Sets the table type to `TYPE RELATION IN @from OUT @to`.
+    ///
+    /// Multiple tables on either side are joined with `|`.
+    ///
+    /// # Example
+    /// ```
+    /// # use surrealex::QueryBuilder;
+    /// let sql = QueryBuilder::define_table("likes")
+    ///     .type_relation(vec!["user"], vec!["post", "comment"])
+    ///     .enforced()
+    ///     .build();
+    /// assert_eq!(
+    ///     sql,
+    ///     "DEFINE TABLE likes TYPE RELATION IN user OUT post | comment ENFORCED"
+    /// );
+    /// ```
+    pub fn type_relation<S: Into<String>>(mut self, from: Vec<S>, to: Vec<S>) -> Self {
+        self.data.table_type = Some(TableType::Relation {
+            from: from.into_iter().map(|s| s.into()).collect(),
+            to: to.into_iter().map(|s| s.into()).collect(),
+            enforced: false,
+        });
+        self
+    }
+
+    /// Adds `ENFORCED` to a `TYPE RELATION` clause.
+    ///
+    /// Has no effect unless [`type_relation`](Self::type_relation) was called first.
+    pub fn enforced(mut self) -> Self {
+        if let Some(TableType::Relation { enforced, .. }) = &mut self.data.table_type {
+            *enforced = true;
+        }
+        self
+    }
+
+    /// Turns the table into a pre-computed view, rendered as `AS SELECT ...`.
+    ///
+    /// # Example
+    /// ```
+    /// # use surrealex::QueryBuilder;
+    /// let sql = QueryBuilder::define_table("adults")
+    ///     .as_select(
+    ///         QueryBuilder::select(surrealex::fields!("id", "name"))
+    ///             .from("person")
+    ///             .r#where("age >= 18"),
+    ///     )
+    ///     .build();
+    /// assert_eq!(
+    ///     sql,
+    ///     "DEFINE TABLE adults AS SELECT id, name FROM person WHERE age >= 18"
+    /// );
+    /// ```
+    pub fn as_select(mut self, query: FromReady) -> Self {
+        self.data.view = Some(query.build());
+        self
+    }
+
+    /// Sets the `CHANGEFEED` clause with the given retention duration.
+    ///
+    /// Accepts either a raw SurrealQL duration string (e.g. `"3d"`) or a
+    /// [`std::time::Duration`], which is automatically converted to SurrealQL syntax.
+    pub fn changefeed(mut self, duration: impl IntoTimeout) -> Self {
+        self.data.changefeed = Some(Changefeed {
+            duration: duration.into_timeout(),
+            include_original: false,
+        });
+        self
+    }
+
+    /// Adds `INCLUDE ORIGINAL` to the `CHANGEFEED` clause.
+    ///
+    /// Has no effect unless [`changefeed`](Self::changefeed) was called first.
+    pub fn include_original(mut self) -> Self {
+        if let Some(changefeed) = &mut self.data.changefeed {
+            changefeed.include_original = true;
+        }
+        self
+    }
+
+    /// Sets the PERMISSIONS clause to `PERMISSIONS NONE`.
+    pub fn permissions_none(mut self) -> Self {
+        self.data.permissions = Some(Permissions::None);
+        self
+    }
+
+    /// Sets the PERMISSIONS clause to `PERMISSIONS FULL`.
+    pub fn permissions_full(mut self) -> Self {
+        self.data.permissions = Some(Permissions::Full);
+        self
+    }
+
+    /// Adds a `FOR @kinds @permission` rule to the PERMISSIONS clause.
+    ///
+    /// Multiple calls accumulate rules. A previous `NONE` or `FULL` clause is
+    /// replaced.
+    ///
+    /// # Example
+    /// ```
+    /// # use surrealex::QueryBuilder;
+    /// use surrealex::types::define::{Permission, PermissionKind};
+    ///
+    /// let sql = QueryBuilder::define_table("post")
+    ///     .permissions_for(vec![PermissionKind::Select], Permission::Full)
+    ///     .permissions_for(
+    ///         vec![PermissionKind::Create, PermissionKind::Update],
+    ///         "user = $auth.id",
+    ///     )
+    ///     .build();
+    /// assert_eq!(
+    ///     sql,
+    ///     "DEFINE TABLE post PERMISSIONS FOR select FULL FOR create, update WHERE user = $auth.id"
+    /// );
+    /// ```
+    pub fn permissions_for(
+        mut self,
+        kinds: Vec<PermissionKind>,
+        permission: impl Into<Permission>,
+    ) -> Self {
+        let rule = PermissionRule {
+            kinds,
+            permission: permission.into(),
+        };
+        match &mut self.data.permissions {
+            Some(Permissions::For(rules)) => rules.push(rule),
+            _ => self.data.permissions = Some(Permissions::For(vec![rule])),
+        }
+        self
+    }
+
+    /// Sets the `COMMENT` clause. The text is rendered as a quoted string.
+    pub fn comment(mut self, comment: &str) -> Self {
+        self.data.comment = Some(comment.to_string());
+        self
+    }
+
+    /// Builds the final DEFINE TABLE query string.
+    pub fn build(self) -> String {
+        let mut query = String::with_capacity(128);
+        push_clause!(query, "DEFINE TABLE");
+
+        if let Some(ref mode) = self.data.mode {
+            push_clause!(query, "{mode}");
+        }
+
+        let name = &self.data.name;
+        push_clause!(query, "{name}");
+
+        if self.data.drop {
+            push_clause!(query, "DROP");
+        }
+
+        if let Some(ref schema) = self.data.schema {
+            push_clause!(query, "{schema}");
+        }
+
+        if let Some(ref table_type) = self.data.table_type {
+            push_clause!(query, "TYPE {table_type}");
+        }
+
+        if let Some(ref view) = self.data.view {
+            push_clause!(query, "AS {view}");
+        }
+
+        if let Some(ref changefeed) = self.data.changefeed {
+            push_clause!(query, "CHANGEFEED {changefeed}");
+        }
+
+        if let Some(ref permissions) = self.data.permissions {
+            push_clause!(query, "PERMISSIONS {permissions}");
+        }
+
+        if let Some(ref comment) = self.data.comment {
+            let comment = quote_str(comment);
+            push_clause!(query, "COMMENT {comment}");
+        }
+
+        query
+    }
+}
+
+impl<V> IntoStatement for DefineTableBuilder<V> {
+    fn into_statement(self) -> String {
+        self.build()
+    }
+}
@@ -0,0 +1,163 @@
+use std::{fmt::Write, marker::PhantomData};
+
+use crate::{
+    enums::Base,
+    internal_macros::push_clause,
+    internal_utils::quote_str,
+    traits::{IntoStatement, IntoTimeout},
+    types::define::{
+        DefineMode,
+        user::{DefineUserData, Role, UserCredential},
+    },
+    versioning::SinceV2,
+};
+
+pub struct DefineUserBuilder<V> {
+    pub data: DefineUserData,
+    pub(crate) version: PhantomData<V>,
+}
+
+impl<V: SinceV2> DefineUserBuilder<V> {
+    /// Adds `OVERWRITE`, replacing the user definition if it already exists.
+    ///
+    /// This replaces any previous `IF NOT EXISTS` modifier.
+    pub fn overwrite(mut self) -> Self {
+        self.data.mode = Some(DefineMode::Overwrite);
+        self
+    }
+
+    /// Adds `IF NOT EXISTS`, leaving an existing user definition untouched.
+    ///
+    /// This replaces any previous `OVERWRITE` modifier.
+    pub fn if_not_exists(mut self) -> Self {
+        self.data.mode = Some(DefineMode::IfNotExists);
+        self
+    }
+
+    /// Sets the `FOR TOKEN` duration.
+    ///
+    /// Only available when targeting SurrealDB 2.0 or newer.
+    pub fn duration_token(mut self, duration: impl IntoTimeout) -> Self {
+        self.data.durations.token = Some(duration.into_timeout());
+        self
+    }
+
+    /// Sets the `FOR SESSION` duration.
+    ///
+    /// Only available when targeting SurrealDB 2.0 or newer.
+    pub fn duration_session(mut self, duration: impl IntoTimeout) -> Self {
+        self.data.durations.session = Some(duration.into_timeout());
+        self
+    }
+}
+
+impl<V> DefineUserBuilder<V> {
+    /// Sets the level the user is defined on. Defaults to `DATABASE`.
+    pub fn on(mut self, base: Base) -> Self {
+        self.data.base = base;
+        self
+    }
+
+    /// Sets a plain-text `PASSWORD`, hashed by the server.
+    ///
+    /// This replaces any previous `PASSHASH`.
+    ///
+    /// # Example
+    /// ```
+    /// # use surrealex::QueryBuilder;
+    /// use surrealex::enums::Base;
+    /// use surrealex::types::define::user::Role;
+    ///
+    /// let sql = QueryBuilder::define_user("admin")
+    ///     .on(Base::Root)
+    ///     .password("s3cret")
+    ///     .role(Role::Owner)
+    ///     .build();
+    /// assert_eq!(sql, "DEFINE USER admin ON ROOT PASSWORD \"s3cret\" ROLES OWNER");
+    /// ```
+    pub fn password(mut self, password: &str) -> Self {
+        self.data.credential = Some(UserCredential::Password(password.to_string()));
+        self
+    }
+
+    /// Sets a pre-computed `PASSHASH`.
+    ///
+    /// This replaces any previous `PASSWORD`.
+    pub fn passhash(mut self, hash: &str) -> Self {
+        self.data.credential = Some(UserCredential::Passhash(hash.to_string()));
+        self
+    }
+
+    /// Appends a role to the `ROLES` clause.
+    pub fn role(mut self, role: Role) -> Self {
+        self.data.roles.push(role);
+        self
+    }
+
+    /// Appends several roles to the `ROLES` clause.
+    pub fn roles(mut self, roles: Vec<Role>) -> Self {
+        self.data.roles.extend(roles);
+        self
+    }
+
+    /// Sets the `COMMENT` clause. The text is rendered as a quoted string.
+    pub fn comment(mut self, comment: &str) -> Self {
+        self.data.comment = Some(comment.to_string());
+        self
+    }
+
+    /// Builds the final DEFINE USER query string.
+    pub fn build(self) -> String {
+        let mut query = String::with_capacity(128);
+        push_clause!(query, "DEFINE USER");
+
+        if let Some(ref mode) = self.data.mode {
+            push_clause!(query, "{mode}");
+        }
+
+        let name = &self.data.name;
+        let base = &self.data.base;
+        push_clause!(query, "{name} ON {base}");
+
+        match self.data.credential {
+            Some(UserCredential::Password(ref password)) => {
+                let password = quote_str(password);
+                push_clause!(query, "PASSWORD {password}");
+            }
+            Some(UserCredential::Passhash(ref hash)) => {
+                let hash = quote_str(hash);
+                push_clause!(query, "PASSHASH {hash}");
+            }
+            None => {}
+        }
+
+        if !self.data.roles.is_empty() {
+            let roles = self
+                .data
+                .roles
+                .iter()
+                .map(|role| role.to_string())
+                .collect::<Vec<String>>()
+                .join(", ");
+            push_clause!(query, "ROLES {roles}");
+        }
+
+        if !self.data.durations.is_empty() {
+            let durations = &self.data.durations;
+            push_clause!(query, "DURATION {durations}");
+        }
+
+        if let Some(ref comment) = self.data.comment {
+            let comment = quote_str(comment);
+            push_clause!(query, "COMMENT {comment}");
+        }
+
+        query
+    }
+}
+
+impl<V> IntoStatement for DefineUserBuilder<V> {
+    fn into_statement(self) -> String {
+        self.build()
+    }
+}
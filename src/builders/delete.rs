@@ -1,7 +1,7 @@
 use crate::{
     enums::{Condition, ExplainClause, ReturnClause},
     internal_macros::push_clause,
-    traits::IntoTimeout,
+    traits::{IntoStatement, IntoTarget, IntoTimeout},
     types::delete::DeleteData,
 };
 use std::fmt::Write;
@@ -144,3 +144,15 @@ impl DeleteBuilder {
         query
     }
 }
+
+impl IntoStatement for DeleteBuilder {
+    fn into_statement(self) -> String {
+        self.build()
+    }
+}
+
+impl IntoTarget for DeleteBuilder {
+    fn into_target(self) -> String {
+        format!("({})", self.build())
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{
+    traits::IntoStatement,
+    types::{for_loop::ForLoopData, script::Param},
+};
+
+/// Builder for `FOR $item IN @iterable { ... }` loops, created via
+/// [`QueryBuilder::r#for`].
+///
+/// The iterable can be a `FromReady` subquery, an array literal (`Vec`) or a
+/// parameter. Use [`param`](Self::param) to reference the loop variable in the
+/// body.
+///
+/// [`QueryBuilder::r#for`]: crate::QueryBuilder::for
+///
+/// # Example
+/// ```
+/// # use surrealex::QueryBuilder;
+/// use surrealex::types::control::Continue;
+///
+/// let for_loop = QueryBuilder::r#for(
+///     "person",
+///     QueryBuilder::select(surrealex::fields!("id")).from("person"),
+/// );
+/// let person = for_loop.param();
+/// let sql = for_loop
+///     .statement(QueryBuilder::r#if(format!("{person}.id = person:tobie"), Continue))
+///     .statement(QueryBuilder::update(person.field("id")).set("active", "true"))
+///     .build();
+/// assert_eq!(
+///     sql,
+///     "FOR $person IN (SELECT id FROM person) { IF $person.id = person:tobie { CONTINUE }; UPDATE $person.id SET active = true }"
+/// );
+/// ```
+pub struct ForLoop {
+    pub data: ForLoopData,
+}
+
+impl ForLoop {
+    /// Returns a handle to the loop variable.
+    pub fn param(&self) -> Param {
+        Param::new(&self.data.item)
+    }
+
+    /// Appends a statement to the loop body.
+    pub fn statement(mut self, statement: impl IntoStatement) -> Self {
+        self.data.body.push(statement.into_statement());
+        self
+    }
+
+    /// Builds the final FOR statement.
+    pub fn build(self) -> String {
+        let item = &self.data.item;
+        let iterable = &self.data.iterable;
+        if self.data.body.is_empty() {
+            format!("FOR ${item} IN {iterable} {{}}")
+        } else {
+            let body = self.data.body.join("; ");
+            format!("FOR ${item} IN {iterable} {{ {body} }}")
+        }
+    }
+}
+
+impl IntoStatement for ForLoop {
+    fn into_statement(self) -> String {
+        self.build()
+    }
+}
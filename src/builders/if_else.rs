@@ -0,0 +1,92 @@
+use std::fmt::Write;
+
+use crate::{
+    enums::Condition,
+    internal_macros::push_clause,
+    traits::IntoStatement,
+    types::if_else::{IfBody, IfBranch, IfData},
+    versioning::if_else::VersionedIf,
+};
+
+/// Builder for `IF` / `ELSE IF` / `ELSE` statements, created via
+/// [`QueryBuilder::r#if`].
+///
+/// Branch bodies accept any statement builder; use a
+/// [`Script`](crate::builders::script::Script) for multi-statement bodies.
+/// When targeting SurrealDB 1.x, single statements are wrapped as
+/// `THEN (..)` and multi-statement bodies as a `THEN { .. }` block.
+///
+/// [`QueryBuilder::r#if`]: crate::QueryBuilder::if
+///
+/// # Example
+/// ```
+/// use surrealex::{QueryBuilder, SurrealV1};
+///
+/// let sql = QueryBuilder::r#if("$before.email != $after.email", QueryBuilder::create("audit"))
+///     .r#else("NONE")
+///     .build();
+/// assert_eq!(sql, "IF $before.email != $after.email { CREATE audit } ELSE { NONE }");
+///
+/// let sql = QueryBuilder::with_version(SurrealV1)
+///     .r#if("$before.email != $after.email", QueryBuilder::create("audit"))
+///     .r#else("NONE")
+///     .build();
+/// assert_eq!(
+///     sql,
+///     "IF $before.email != $after.email THEN (CREATE audit) ELSE (NONE) END"
+/// );
+/// ```
+pub struct If<V> {
+    pub data: IfData,
+    pub(crate) renderer: V,
+}
+
+impl<V: VersionedIf> If<V> {
+    /// Appends an `ELSE IF @condition` branch.
+    pub fn else_if<T: Into<Condition>>(mut self, condition: T, body: impl IntoStatement) -> Self {
+        self.data.branches.push(IfBranch {
+            condition: condition.into(),
+            body: IfBody::new(body),
+        });
+        self
+    }
+
+    /// Sets the final `ELSE` branch, replacing any previous one.
+    pub fn r#else(mut self, body: impl IntoStatement) -> Self {
+        self.data.else_body = Some(IfBody::new(body));
+        self
+    }
+
+    /// Builds the final IF statement using the configured version's syntax.
+    pub fn build(self) -> String {
+        render_if(&self.renderer, &self.data)
+    }
+}
+
+/// Renders an IF statement with the given version's syntax.
+pub(crate) fn render_if<V: VersionedIf>(renderer: &V, data: &IfData) -> String {
+    let mut query = String::with_capacity(128);
+
+    for (i, branch) in data.branches.iter().enumerate() {
+        let keyword = if i == 0 { "IF" } else { "ELSE IF" };
+        let branch = renderer.guarded_branch(&branch.condition, &branch.body);
+        push_clause!(query, "{keyword} {branch}");
+    }
+
+    if let Some(ref body) = data.else_body {
+        let branch = renderer.else_branch(body);
+        push_clause!(query, "ELSE {branch}");
+    }
+
+    if let Some(terminator) = renderer.terminator() {
+        push_clause!(query, "{terminator}");
+    }
+
+    query
+}
+
+impl<V: VersionedIf> IntoStatement for If<V> {
+    fn into_statement(self) -> String {
+        self.build()
+    }
+}
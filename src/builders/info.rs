@@ -0,0 +1,117 @@
+use std::{fmt::Write, marker::PhantomData};
+
+use crate::{
+    enums::Base,
+    internal_macros::push_clause,
+    traits::IntoStatement,
+    types::info::{InfoData, InfoScope},
+    versioning::SinceV2,
+};
+
+/// Entry point for INFO statements, created via [`QueryBuilder::info`].
+///
+/// Each method selects the scope to inspect and returns an [`InfoReady`]
+/// builder.
+///
+/// [`QueryBuilder::info`]: crate::QueryBuilder::info
+pub struct InfoBuilder<V> {
+    pub(crate) version: PhantomData<V>,
+}
+
+impl<V> InfoBuilder<V> {
+    fn scope(self, scope: InfoScope) -> InfoReady<V> {
+        InfoReady {
+            data: InfoData {
+                scope,
+                ..Default::default()
+            },
+            version: PhantomData,
+        }
+    }
+
+    /// Inspects the root: `INFO FOR ROOT`.
+    pub fn root(self) -> InfoReady<V> {
+        self.scope(InfoScope::Root)
+    }
+
+    /// Inspects the current namespace: `INFO FOR NS`.
+    pub fn namespace(self) -> InfoReady<V> {
+        self.scope(InfoScope::Namespace)
+    }
+
+    /// Inspects the current database: `INFO FOR DB`.
+    pub fn database(self) -> InfoReady<V> {
+        self.scope(InfoScope::Database)
+    }
+
+    /// Inspects a table: `INFO FOR TABLE @name`.
+    ///
+    /// # Example
+    /// ```
+    /// # use surrealex::QueryBuilder;
+    /// let sql = QueryBuilder::info().table("user").structure().build();
+    /// assert_eq!(sql, "INFO FOR TABLE user STRUCTURE");
+    /// ```
+    pub fn table(self, name: &str) -> InfoReady<V> {
+        self.scope(InfoScope::Table(name.to_string()))
+    }
+
+    /// Inspects a system user: `INFO FOR USER @name`.
+    pub fn user(self, name: &str) -> InfoReady<V> {
+        self.scope(InfoScope::User {
+            name: name.to_string(),
+            base: None,
+        })
+    }
+
+    /// Inspects a system user defined on a specific level:
+    /// `INFO FOR USER @name ON @base`.
+    pub fn user_on(self, name: &str, base: Base) -> InfoReady<V> {
+        self.scope(InfoScope::User {
+            name: name.to_string(),
+            base: Some(base),
+        })
+    }
+}
+
+pub struct InfoReady<V> {
+    pub data: InfoData,
+    pub(crate) version: PhantomData<V>,
+}
+
+impl<V: SinceV2> InfoReady<V> {
+    /// Adds `STRUCTURE`, returning definitions as structured objects.
+    ///
+    /// Only available when targeting SurrealDB 2.0 or newer.
+    ///
+    /// ```compile_fail
+    /// use surrealex::{QueryBuilder, SurrealV1};
+    ///
+    /// QueryBuilder::with_version(SurrealV1).info().root().structure();
+    /// ```
+    pub fn structure(mut self) -> Self {
+        self.data.structure = true;
+        self
+    }
+}
+
+impl<V> InfoReady<V> {
+    /// Builds the final INFO query string.
+    pub fn build(self) -> String {
+        let mut query = String::with_capacity(32);
+        let scope = &self.data.scope;
+        push_clause!(query, "INFO FOR {scope}");
+
+        if self.data.structure {
+            push_clause!(query, "STRUCTURE");
+        }
+
+        query
+    }
+}
+
+impl<V> IntoStatement for InfoReady<V> {
+    fn into_statement(self) -> String {
+        self.build()
+    }
+}
@@ -1,6 +1,7 @@
 use crate::{
     enums::ReturnClause,
     internal_macros::push_clause,
+    traits::{IntoStatement, IntoTarget},
     types::{
         create::SetField,
         insert::{InsertContent, InsertData},
@@ -269,3 +270,15 @@ impl InsertBuilder {
         query
     }
 }
+
+impl IntoStatement for InsertBuilder {
+    fn into_statement(self) -> String {
+        self.build()
+    }
+}
+
+impl IntoTarget for InsertBuilder {
+    fn into_target(self) -> String {
+        format!("({})", self.build())
+    }
+}
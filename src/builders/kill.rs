@@ -0,0 +1,21 @@
+use crate::{traits::IntoStatement, types::kill::KillData};
+
+/// Builder for `KILL` statements, created via [`QueryBuilder::kill`].
+///
+/// [`QueryBuilder::kill`]: crate::QueryBuilder::kill
+pub struct KillBuilder {
+    pub data: KillData,
+}
+
+impl KillBuilder {
+    /// Builds the final KILL query string.
+    pub fn build(self) -> String {
+        format!("KILL {}", self.data.target)
+    }
+}
+
+impl IntoStatement for KillBuilder {
+    fn into_statement(self) -> String {
+        self.build()
+    }
+}
@@ -0,0 +1,104 @@
+use std::fmt::Write;
+
+use crate::{
+    enums::Condition,
+    internal_macros::push_clause,
+    traits::IntoStatement,
+    types::{live::LiveData, select::render_fields},
+};
+
+/// Builder for `LIVE SELECT` statements, created via [`QueryBuilder::live`]
+/// or [`QueryBuilder::live_diff`].
+///
+/// Only the clauses legal in live queries are exposed: `FROM`, `WHERE` and
+/// `FETCH`.
+///
+/// [`QueryBuilder::live`]: crate::QueryBuilder::live
+/// [`QueryBuilder::live_diff`]: crate::QueryBuilder::live_diff
+pub struct LiveBuilder {
+    pub data: LiveData,
+}
+
+impl LiveBuilder {
+    /// Sets the table to watch.
+    ///
+    /// # Example
+    /// ```
+    /// # use surrealex::QueryBuilder;
+    /// use surrealex::enums::SelectionFields;
+    ///
+    /// let sql = QueryBuilder::live(SelectionFields::All)
+    ///     .from("order")
+    ///     .r#where("status = 'open'")
+    ///     .fetch(vec!["customer"])
+    ///     .build();
+    /// assert_eq!(
+    ///     sql,
+    ///     "LIVE SELECT * FROM order WHERE status = 'open' FETCH customer"
+    /// );
+    /// ```
+    pub fn from(mut self, table: &str) -> LiveReady {
+        self.data.table = table.to_string();
+        LiveReady { data: self.data }
+    }
+}
+
+pub struct LiveReady {
+    pub data: LiveData,
+}
+
+impl LiveReady {
+    /// Appends a WHERE condition. Multiple calls are joined with `AND`.
+    pub fn r#where<T: Into<Condition>>(mut self, condition: T) -> Self {
+        self.data.where_clause.push(condition.into());
+        self
+    }
+
+    pub fn fetch(mut self, fields: Vec<&str>) -> Self {
+        self.data
+            .fetch_fields
+            .extend(fields.iter().map(|s| s.to_string()));
+        self
+    }
+
+    /// Builds the final LIVE SELECT query string.
+    pub fn build(self) -> String {
+        let mut query = String::with_capacity(128);
+        push_clause!(query, "LIVE SELECT");
+
+        if self.data.diff {
+            push_clause!(query, "DIFF");
+        } else {
+            let fields = render_fields(&self.data.fields);
+            push_clause!(query, "{fields}");
+        }
+
+        let table = &self.data.table;
+        push_clause!(query, "FROM {table}");
+
+        if !self.data.where_clause.is_empty() {
+            let conditions: String = self
+                .data
+                .where_clause
+                .iter()
+                .map(|cond| cond.to_string())
+                .collect::<Vec<String>>()
+                .join(" AND ");
+
+            push_clause!(query, "WHERE {conditions}");
+        }
+
+        if !self.data.fetch_fields.is_empty() {
+            let fetch_fields = self.data.fetch_fields.join(", ");
+            push_clause!(query, "FETCH {fetch_fields}");
+        }
+
+        query
+    }
+}
+
+impl IntoStatement for LiveReady {
+    fn into_statement(self) -> String {
+        self.build()
+    }
+}
@@ -0,0 +1,172 @@
+use crate::{
+    enums::ReturnClause,
+    internal_macros::push_clause,
+    traits::{IntoStatement, IntoTarget, IntoTimeout},
+    types::{
+        create::{ContentMode, SetField},
+        relate::RelateData,
+    },
+};
+use std::fmt::Write;
+
+pub struct RelateBuilder {
+    pub data: RelateData,
+}
+
+impl RelateBuilder {
+    /// Switches the statement from `RELATE ...` to `RELATE ONLY ...`.
+    ///
+    /// **Note:** SurrealDB expects a single-result `RETURN` when using `ONLY`.
+    /// The builder does not enforce this — the server will validate it at runtime.
+    pub fn only(mut self) -> Self {
+        self.data.only = true;
+        self
+    }
+
+    /// Sets the data-setting mode to `CONTENT @value`.
+    ///
+    /// This replaces any previous `CONTENT` or `SET` clause.
+    ///
+    /// # Example
+    /// ```
+    /// # use surrealex::QueryBuilder;
+    /// let sql = QueryBuilder::relate("person:tobie", "wrote", "article:surreal")
+    ///     .content("{ source: 'Apple notes' }")
+    ///     .build();
+    /// assert_eq!(
+    ///     sql,
+    ///     "RELATE person:tobie->wrote->article:surreal CONTENT { source: 'Apple notes' }"
+    /// );
+    /// ```
+    pub fn content(mut self, value: &str) -> Self {
+        self.data.content = Some(ContentMode::Content(value.to_string()));
+        self
+    }
+
+    /// Adds a `SET field = value` assignment.
+    ///
+    /// Multiple calls accumulate assignments. If a `CONTENT` clause was previously
+    /// set, it is replaced by the `SET` clause.
+    ///
+    /// `value` is a raw SurrealQL expression, a script [`Param`](crate::types::script::Param)
+    /// or a statement builder, which is rendered as a parenthesized subquery.
+    ///
+    /// # Example
+    /// ```
+    /// # use surrealex::QueryBuilder;
+    /// let sql = QueryBuilder::relate("person:tobie", "wrote", "article:surreal")
+    ///     .set("time.written", "time::now()")
+    ///     .build();
+    /// assert_eq!(
+    ///     sql,
+    ///     "RELATE person:tobie->wrote->article:surreal SET time.written = time::now()"
+    /// );
+    /// ```
+    pub fn set(mut self, field: &str, value: impl IntoTarget) -> Self {
+        let value = value.into_target();
+        let set_field = SetField {
+            field: field.to_string(),
+            value,
+        };
+        match &mut self.data.content {
+            Some(ContentMode::Set(fields)) => fields.push(set_field),
+            _ => self.data.content = Some(ContentMode::Set(vec![set_field])),
+        }
+        self
+    }
+
+    /// Sets the RETURN clause to `RETURN NONE`.
+    pub fn return_none(mut self) -> Self {
+        self.data.return_clause = Some(ReturnClause::None);
+        self
+    }
+
+    /// Sets the RETURN clause to `RETURN BEFORE`.
+    pub fn return_before(mut self) -> Self {
+        self.data.return_clause = Some(ReturnClause::Before);
+        self
+    }
+
+    /// Sets the RETURN clause to `RETURN AFTER`.
+    pub fn return_after(mut self) -> Self {
+        self.data.return_clause = Some(ReturnClause::After);
+        self
+    }
+
+    /// Sets the RETURN clause to `RETURN DIFF`.
+    pub fn return_diff(mut self) -> Self {
+        self.data.return_clause = Some(ReturnClause::Diff);
+        self
+    }
+
+    /// Sets the RETURN clause to `RETURN <param1>, <param2>, ...`.
+    pub fn return_params<S: Into<String>>(mut self, params: Vec<S>) -> Self {
+        self.data.return_clause = Some(ReturnClause::Params(
+            params.into_iter().map(|s| s.into()).collect(),
+        ));
+        self
+    }
+
+    /// Sets the RETURN clause to `RETURN VALUE <field>`.
+    pub fn return_value(mut self, field: &str) -> Self {
+        self.data.return_clause = Some(ReturnClause::Value(field.to_string()));
+        self
+    }
+
+    /// Sets the TIMEOUT clause.
+    ///
+    /// Accepts either a raw SurrealQL duration string (e.g. `"500ms"`, `"2s"`, `"1m"`)
+    /// or a [`std::time::Duration`], which is automatically converted to SurrealQL syntax.
+    pub fn timeout(mut self, duration: impl IntoTimeout) -> Self {
+        self.data.timeout = Some(duration.into_timeout());
+        self
+    }
+
+    /// Adds the `PARALLEL` keyword to the statement.
+    pub fn parallel(mut self) -> Self {
+        self.data.parallel = true;
+        self
+    }
+
+    /// Builds the final RELATE query string.
+    pub fn build(self) -> String {
+        let mut query = String::with_capacity(128);
+        let RelateData { from, edge, to, .. } = &self.data;
+
+        if self.data.only {
+            push_clause!(query, "RELATE ONLY {from}->{edge}->{to}");
+        } else {
+            push_clause!(query, "RELATE {from}->{edge}->{to}");
+        }
+
+        if let Some(ref content) = self.data.content {
+            push_clause!(query, "{content}");
+        }
+
+        if let Some(ref rc) = self.data.return_clause {
+            push_clause!(query, "RETURN {rc}");
+        }
+
+        if let Some(ref duration) = self.data.timeout {
+            push_clause!(query, "TIMEOUT {duration}");
+        }
+
+        if self.data.parallel {
+            push_clause!(query, "PARALLEL");
+        }
+
+        query
+    }
+}
+
+impl IntoStatement for RelateBuilder {
+    fn into_statement(self) -> String {
+        self.build()
+    }
+}
+
+impl IntoTarget for RelateBuilder {
+    fn into_target(self) -> String {
+        format!("({})", self.build())
+    }
+}
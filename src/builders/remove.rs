@@ -0,0 +1,202 @@
+use std::{fmt::Write, marker::PhantomData};
+
+use crate::{
+    SurrealV1,
+    enums::Base,
+    internal_macros::push_clause,
+    traits::IntoStatement,
+    types::{
+        define::access::TokenBase,
+        remove::{RemoveData, RemoveResource},
+    },
+    versioning::SinceV2,
+};
+
+/// Entry point for REMOVE statements, created via [`QueryBuilder::remove`].
+///
+/// Each method selects the kind of resource to remove and returns a
+/// [`RemoveReady`] builder.
+///
+/// [`QueryBuilder::remove`]: crate::QueryBuilder::remove
+pub struct RemoveBuilder<V> {
+    pub(crate) version: PhantomData<V>,
+}
+
+impl<V> RemoveBuilder<V> {
+    fn resource(self, resource: RemoveResource, name: &str) -> RemoveReady<V> {
+        RemoveReady {
+            data: RemoveData {
+                resource,
+                name: name.to_string(),
+                ..Default::default()
+            },
+            version: PhantomData,
+        }
+    }
+
+    /// Removes a namespace: `REMOVE NAMESPACE @name`.
+    pub fn namespace(self, name: &str) -> RemoveReady<V> {
+        self.resource(RemoveResource::Namespace, name)
+    }
+
+    /// Removes a database: `REMOVE DATABASE @name`.
+    pub fn database(self, name: &str) -> RemoveReady<V> {
+        self.resource(RemoveResource::Database, name)
+    }
+
+    /// Removes a table: `REMOVE TABLE @name`.
+    ///
+    /// # Example
+    /// ```
+    /// # use surrealex::QueryBuilder;
+    /// let sql = QueryBuilder::remove().table("user").if_exists().build();
+    /// assert_eq!(sql, "REMOVE TABLE IF EXISTS user");
+    /// ```
+    pub fn table(self, name: &str) -> RemoveReady<V> {
+        self.resource(RemoveResource::Table, name)
+    }
+
+    /// Removes a field: `REMOVE FIELD @name ON TABLE @table`.
+    pub fn field(self, name: &str, table: &str) -> RemoveReady<V> {
+        let table = table.to_string();
+        self.resource(RemoveResource::Field { table }, name)
+    }
+
+    /// Removes an index: `REMOVE INDEX @name ON TABLE @table`.
+    pub fn index(self, name: &str, table: &str) -> RemoveReady<V> {
+        let table = table.to_string();
+        self.resource(RemoveResource::Index { table }, name)
+    }
+
+    /// Removes an event: `REMOVE EVENT @name ON TABLE @table`.
+    pub fn event(self, name: &str, table: &str) -> RemoveReady<V> {
+        let table = table.to_string();
+        self.resource(RemoveResource::Event { table }, name)
+    }
+
+    /// Removes a function: `REMOVE FUNCTION @name`.
+    ///
+    /// `name` includes the `fn::` prefix (e.g., `"fn::greet"`).
+    pub fn function(self, name: &str) -> RemoveReady<V> {
+        self.resource(RemoveResource::Function, name)
+    }
+
+    /// Removes a parameter: `REMOVE PARAM $@name`.
+    ///
+    /// A leading `$` in `name` is optional.
+    pub fn param(self, name: &str) -> RemoveReady<V> {
+        let name = format!("${}", name.trim_start_matches('$'));
+        self.resource(RemoveResource::Param, &name)
+    }
+
+    /// Removes an analyzer: `REMOVE ANALYZER @name`.
+    pub fn analyzer(self, name: &str) -> RemoveReady<V> {
+        self.resource(RemoveResource::Analyzer, name)
+    }
+
+    /// Removes a system user: `REMOVE USER @name ON @base`.
+    pub fn user(self, name: &str, base: Base) -> RemoveReady<V> {
+        self.resource(RemoveResource::User { base }, name)
+    }
+}
+
+impl<V: SinceV2> RemoveBuilder<V> {
+    /// Removes an access method: `REMOVE ACCESS @name ON @base`.
+    ///
+    /// Only available when targeting SurrealDB 2.0 or newer; SurrealDB 1.x
+    /// uses [`scope`](RemoveBuilder::scope) / [`token`](RemoveBuilder::token)
+    /// instead.
+    pub fn access(self, name: &str, base: Base) -> RemoveReady<V> {
+        self.resource(RemoveResource::Access { base }, name)
+    }
+}
+
+impl RemoveBuilder<SurrealV1> {
+    /// Removes a SurrealDB 1.x scope, along with its tokens:
+    /// `REMOVE SCOPE @name`.
+    ///
+    /// Undoes a record access defined with
+    /// [`DefineAccessBuilder::type_record`](crate::builders::define::access::DefineAccessBuilder::type_record).
+    ///
+    /// # Example
+    /// ```
+    /// use surrealex::{QueryBuilder, SurrealV1};
+    ///
+    /// let sql = QueryBuilder::with_version(SurrealV1)
+    ///     .remove()
+    ///     .scope("account")
+    ///     .build();
+    /// assert_eq!(sql, "REMOVE SCOPE account");
+    /// ```
+    pub fn scope(self, name: &str) -> RemoveReady<SurrealV1> {
+        self.resource(RemoveResource::Scope, name)
+    }
+
+    /// Removes a SurrealDB 1.x token: `REMOVE TOKEN @name ON @base`.
+    ///
+    /// Undoes a JWT access defined with
+    /// [`DefineAccessBuilder::type_jwt`](crate::builders::define::access::DefineAccessBuilder::type_jwt).
+    pub fn token(self, name: &str, base: TokenBase) -> RemoveReady<SurrealV1> {
+        let base = base.into();
+        self.resource(RemoveResource::Token { base }, name)
+    }
+}
+
+/// A REMOVE statement with its resource set, ready to be built.
+pub struct RemoveReady<V> {
+    pub data: RemoveData,
+    pub(crate) version: PhantomData<V>,
+}
+
+impl<V: SinceV2> RemoveReady<V> {
+    /// Adds `IF EXISTS`, turning the removal of a missing resource into a no-op.
+    ///
+    /// Only available when targeting SurrealDB 2.0 or newer.
+    ///
+    /// ```compile_fail
+    /// use surrealex::{QueryBuilder, SurrealV1};
+    ///
+    /// QueryBuilder::with_version(SurrealV1)
+    ///     .remove()
+    ///     .table("user")
+    ///     .if_exists();
+    /// ```
+    pub fn if_exists(mut self) -> Self {
+        self.data.if_exists = true;
+        self
+    }
+}
+
+impl<V> RemoveReady<V> {
+    /// Builds the final REMOVE query string.
+    pub fn build(self) -> String {
+        let mut query = String::with_capacity(64);
+        let resource = &self.data.resource;
+        push_clause!(query, "REMOVE {resource}");
+
+        if self.data.if_exists {
+            push_clause!(query, "IF EXISTS");
+        }
+
+        let name = &self.data.name;
+        push_clause!(query, "{name}");
+
+        match resource {
+            RemoveResource::Field { table }
+            | RemoveResource::Index { table }
+            | RemoveResource::Event { table } => push_clause!(query, "ON TABLE {table}"),
+            RemoveResource::Access { base }
+            | RemoveResource::User { base }
+            | RemoveResource::Token { base } => push_clause!(query, "ON {base}"),
+            _ => {}
+        }
+
+        query
+    }
+}
+
+impl<V> IntoStatement for RemoveReady<V> {
+    fn into_statement(self) -> String {
+        self.build()
+    }
+}
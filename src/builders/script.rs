@@ -0,0 +1,80 @@
+use crate::{
+    traits::{IntoStatement, IntoTarget},
+    types::script::{Param, ScriptData},
+};
+
+/// An ordered list of statements and `LET` bindings, rendered joined with `;`.
+///
+/// Created via [`QueryBuilder::script`]. Statements chain like the other
+/// builders, while [`r#let`](Self::let) takes `&mut self` so it can hand back a
+/// [`Param`] for use in later statements.
+///
+/// [`QueryBuilder::script`]: crate::QueryBuilder::script
+///
+/// # Example
+/// ```
+/// # use surrealex::QueryBuilder;
+/// use surrealex::enums::Condition;
+///
+/// let mut script = QueryBuilder::script();
+/// let user = script.r#let(
+///     "user",
+///     QueryBuilder::select(surrealex::fields!("*")).from_only("user:tobie"),
+/// );
+/// let sql = script
+///     .statement(
+///         QueryBuilder::update("post")
+///             .set("author", &user)
+///             .r#where(Condition::new(format!("email = {}", user.field("email")))),
+///     )
+///     .build();
+/// assert_eq!(
+///     sql,
+///     "LET $user = (SELECT * FROM ONLY user:tobie); UPDATE post SET author = $user WHERE email = $user.email"
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Script {
+    pub data: ScriptData,
+}
+
+impl Script {
+    /// Appends a `LET $name = @value` binding and returns a handle to `$name`.
+    ///
+    /// A leading `$` in `name` is optional. Statement builders are rendered as
+    /// parenthesized subqueries.
+    pub fn r#let(&mut self, name: &str, value: impl IntoTarget) -> Param {
+        let param = Param::new(name);
+        let value = value.into_target();
+        self.data.statements.push(format!("LET {param} = {value}"));
+        param
+    }
+
+    /// Appends a statement to the script.
+    pub fn statement(mut self, statement: impl IntoStatement) -> Self {
+        self.data.statements.push(statement.into_statement());
+        self
+    }
+
+    /// Appends a `RETURN @value` statement to the script.
+    pub fn r#return(mut self, value: impl IntoTarget) -> Self {
+        let value = value.into_target();
+        self.data.statements.push(format!("RETURN {value}"));
+        self
+    }
+
+    /// Builds the final script, with statements joined by `; `.
+    pub fn build(self) -> String {
+        self.data.statements.join("; ")
+    }
+}
+
+impl IntoStatement for Script {
+    fn into_statement(self) -> String {
+        self.build()
+    }
+
+    fn is_multi_statement(&self) -> bool {
+        self.data.statements.len() > 1
+    }
+}
@@ -3,8 +3,11 @@ use std::fmt::Write;
 use crate::{
     enums::{Condition, ExplainClause},
     internal_macros::push_clause,
-    traits::ToSelectField,
-    types::select::{GraphTraversalParams, OrderOptions, OrderTerm, SelectData, SelectField},
+    traits::{IntoStatement, IntoTarget, ToSelectField},
+    types::select::{
+        GraphTraversalParams, GroupClause, OrderOptions, OrderTerm, SelectData, SelectError,
+        SelectField, render_fields,
+    },
     versioning::select::VersionedSelect,
 };
 
@@ -31,6 +34,25 @@ impl<V: VersionedSelect> SelectBuilder<V> {
         self
     }
 
+    /// Adds fields to the `OMIT` clause, excluding them from the projection.
+    /// Multiple calls accumulate fields.
+    ///
+    /// # Example
+    /// ```
+    /// # use surrealex::QueryBuilder;
+    /// use surrealex::enums::SelectionFields;
+    ///
+    /// let sql = QueryBuilder::select(SelectionFields::All)
+    ///     .omit(vec!["password", "secrets"])
+    ///     .from("user")
+    ///     .build();
+    /// assert_eq!(sql, "SELECT * OMIT password, secrets FROM user");
+    /// ```
+    pub fn omit(mut self, fields: Vec<&str>) -> Self {
+        self.data.omit.extend(fields.iter().map(|s| s.to_string()));
+        self
+    }
+
     pub fn from(mut self, table: &str) -> FromReady {
         self.data.table = Some(table.to_string());
         self.data.only = false;
@@ -48,6 +70,60 @@ impl<V: VersionedSelect> SelectBuilder<V> {
     }
 }
 
+/// Builder for `SELECT VALUE` statements, created via
+/// [`QueryBuilder::select_value`] and its subquery and traversal variants.
+///
+/// A `SELECT VALUE` projects exactly one unaliased expression, so projecting
+/// a new expression replaces the current one. `OMIT` only applies to object
+/// projections and isn't available here:
+///
+/// ```compile_fail
+/// # use surrealex::QueryBuilder;
+/// QueryBuilder::select_value("name").omit(vec!["password"]);
+/// ```
+///
+/// [`QueryBuilder::select_value`]: crate::QueryBuilder::select_value
+pub struct SelectValueBuilder<V> {
+    pub data: SelectData,
+    pub(crate) renderer: V,
+}
+
+impl<V: VersionedSelect> SelectValueBuilder<V> {
+    /// Replaces the projected expression with a graph traversal. Any alias on
+    /// the traversal is dropped.
+    ///
+    /// SurrealDB 1.x has no object destructuring and expands each traversed
+    /// field into its own expression, so only the first field is projected
+    /// there.
+    pub fn graph_traverse(mut self, params: GraphTraversalParams) -> Self {
+        self.data.fields.clear();
+        self.renderer.graph_traverse(&mut self.data, params);
+        self.data.fields.truncate(1);
+        for field in &mut self.data.fields {
+            field.alias = None;
+        }
+        self
+    }
+
+    /// Replaces the projected expression with a parenthesized subquery.
+    pub fn subquery(mut self, subquery: FromReady) -> Self {
+        self.data.fields = vec![subquery.to_select_field()];
+        self
+    }
+
+    pub fn from(mut self, table: &str) -> FromReady {
+        self.data.table = Some(table.to_string());
+        self.data.only = false;
+        FromReady { data: self.data }
+    }
+
+    pub fn from_only(mut self, table: &str) -> FromReady {
+        self.data.table = Some(table.to_string());
+        self.data.only = true;
+        FromReady { data: self.data }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct FromReady {
     data: SelectData,
@@ -59,6 +135,62 @@ impl FromReady {
         self
     }
 
+    /// Adds fields to the `SPLIT` clause. Multiple calls accumulate fields.
+    ///
+    /// Each value of a split array field is returned as a separate row. `SPLIT`
+    /// is applied before `GROUP BY`, `ORDER BY` and `LIMIT`.
+    ///
+    /// # Example
+    /// ```
+    /// # use surrealex::QueryBuilder;
+    /// let sql = QueryBuilder::select(surrealex::fields!("name", "emails"))
+    ///     .from("user")
+    ///     .split(vec!["emails"])
+    ///     .build();
+    /// assert_eq!(sql, "SELECT name, emails FROM user SPLIT emails");
+    /// ```
+    pub fn split(mut self, fields: Vec<&str>) -> Self {
+        self.data.split.extend(fields.iter().map(|s| s.to_string()));
+        self
+    }
+
+    /// Adds fields to the `GROUP BY` clause. Multiple calls accumulate fields,
+    /// and an empty list is ignored.
+    ///
+    /// A previous [`group_all`](Self::group_all) is replaced. Use
+    /// [`try_build`](Self::try_build) to check that every projected field is
+    /// grouped or aggregated.
+    ///
+    /// # Example
+    /// ```
+    /// # use surrealex::QueryBuilder;
+    /// let sql = QueryBuilder::select(surrealex::fields!("country", ("count()", "total")))
+    ///     .from("user")
+    ///     .group_by(vec!["country"])
+    ///     .try_build()
+    ///     .unwrap();
+    /// assert_eq!(sql, "SELECT country, count() AS total FROM user GROUP BY country");
+    /// ```
+    pub fn group_by(mut self, fields: Vec<&str>) -> Self {
+        if fields.is_empty() {
+            return self;
+        }
+        let fields = fields.iter().map(|s| s.to_string());
+        match &mut self.data.group {
+            Some(GroupClause::By(existing)) => existing.extend(fields),
+            _ => self.data.group = Some(GroupClause::By(fields.collect())),
+        }
+        self
+    }
+
+    /// Sets the `GROUP ALL` clause, aggregating over every matched record.
+    ///
+    /// Any previous `GROUP BY` fields are replaced.
+    pub fn group_all(mut self) -> Self {
+        self.data.group = Some(GroupClause::All);
+        self
+    }
+
     /// Adds an `ORDER BY` term for the given field and options.
     ///
     /// Trailing SurrealDB order modifiers embedded in `field` (`ASC`, `DESC`,
@@ -116,26 +248,80 @@ impl FromReady {
         self
     }
 
+    /// Builds the query, first checking that a grouped statement only projects
+    /// grouped fields and aggregates, and that a `SELECT VALUE` statement
+    /// projects exactly one expression.
+    ///
+    /// A projected field is accepted when its expression or alias appears in
+    /// the `GROUP BY` clause, or when it is a call to one of SurrealDB's
+    /// aggregate functions (e.g. `count()`, `math::sum(..)`,
+    /// `array::group(..)`). Other expressions, including non-aggregate
+    /// function calls, must be grouped. Ungrouped statements are never
+    /// rejected.
+    ///
+    /// ```
+    /// # use surrealex::QueryBuilder;
+    /// use surrealex::types::select::SelectError;
+    ///
+    /// let err = QueryBuilder::select(surrealex::fields!("name", "count()"))
+    ///     .from("user")
+    ///     .group_all()
+    ///     .try_build()
+    ///     .unwrap_err();
+    /// assert_eq!(err, SelectError::UngroupedFields(vec!["name".to_string()]));
+    /// ```
+    pub fn try_build(self) -> Result<String, SelectError> {
+        if self.data.value && self.data.fields.len() != 1 {
+            let fields = self.data.fields.iter().map(|f| f.name.clone()).collect();
+            return Err(SelectError::MultipleValueExpressions(fields));
+        }
+        if let Some(ref group) = self.data.group {
+            let grouped: &[String] = match group {
+                GroupClause::All => &[],
+                GroupClause::By(fields) => fields,
+            };
+            let ungrouped = self
+                .data
+                .fields
+                .iter()
+                .filter(|field| {
+                    let is_grouped = grouped
+                        .iter()
+                        .any(|g| *g == field.name || field.alias.as_deref() == Some(g.as_str()));
+                    !is_grouped && !is_aggregate(&field.name)
+                })
+                .map(|field| field.name.clone())
+                .collect::<Vec<String>>();
+            if !ungrouped.is_empty() {
+                return Err(SelectError::UngroupedFields(ungrouped));
+            }
+        }
+        Ok(self.build())
+    }
+
     pub fn build(self) -> String {
         let mut query = String::with_capacity(128);
         push_clause!(query, "SELECT");
 
-        let fields: String = self
-            .data
-            .fields
-            .iter()
-            .map(|field| {
-                if let Some(alias) = &field.alias {
-                    format!("{} AS {}", field.name, alias)
-                } else {
-                    field.name.clone()
-                }
-            })
-            .collect::<Vec<String>>()
-            .join(", ");
+        if self.data.value {
+            push_clause!(query, "VALUE");
+        }
+
+        // A `SELECT VALUE` renders a single expression.
+        let projected = if self.data.value {
+            &self.data.fields[..self.data.fields.len().min(1)]
+        } else {
+            &self.data.fields[..]
+        };
+        let fields = render_fields(projected);
 
         push_clause!(query, "{fields}");
 
+        if !self.data.omit.is_empty() {
+            let omit_fields = self.data.omit.join(", ");
+            push_clause!(query, "OMIT {omit_fields}");
+        }
+
         if let Some(table) = &self.data.table {
             let only = if self.data.only { " ONLY" } else { "" };
             push_clause!(query, "FROM{only} {table}");
@@ -153,6 +339,15 @@ impl FromReady {
             push_clause!(query, "WHERE {conditions}");
         }
 
+        if !self.data.split.is_empty() {
+            let split_fields = self.data.split.join(", ");
+            push_clause!(query, "SPLIT {split_fields}");
+        }
+
+        if let Some(ref group) = self.data.group {
+            push_clause!(query, "{group}");
+        }
+
         if !self.data.order_by.is_empty() {
             let order_terms = self.data.order_by.join(", ");
             push_clause!(query, "ORDER BY {order_terms}");
@@ -179,6 +374,67 @@ impl FromReady {
     }
 }
 
+/// Functions SurrealDB accepts as aggregates in a grouped SELECT.
+const AGGREGATE_FUNCTIONS: &[&str] = &[
+    "array::distinct",
+    "array::first",
+    "array::flatten",
+    "array::group",
+    "array::last",
+    "count",
+    "math::bottom",
+    "math::interquartile",
+    "math::max",
+    "math::mean",
+    "math::median",
+    "math::midhinge",
+    "math::min",
+    "math::mode",
+    "math::nearestrank",
+    "math::percentile",
+    "math::sample",
+    "math::spread",
+    "math::stddev",
+    "math::sum",
+    "math::top",
+    "math::trimean",
+    "math::variance",
+    "time::max",
+    "time::min",
+];
+
+/// Returns `true` when `expr` is a single call to an aggregate function,
+/// i.e. the parenthesis opening its arguments closes at the end of `expr`.
+fn is_aggregate(expr: &str) -> bool {
+    let expr = expr.trim();
+    let Some((function, args)) = expr.split_once('(') else {
+        return false;
+    };
+    let function = function.trim().to_ascii_lowercase();
+    if !AGGREGATE_FUNCTIONS.contains(&function.as_str()) {
+        return false;
+    }
+
+    let mut depth = 1;
+    let mut quote = None;
+    for (i, c) in args.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"') => quote = Some(c),
+            (None, '(') => depth += 1,
+            (None, ')') => {
+                depth -= 1;
+                if depth == 0 {
+                    return i == args.len() - 1;
+                }
+            }
+            _ => {}
+        }
+    }
+    false
+}
+
 impl ToSelectField for FromReady {
     fn to_select_field(self) -> SelectField {
         let subquery = self.build();
@@ -200,3 +456,15 @@ impl ToSelectField for (FromReady, &str) {
         }
     }
 }
+
+impl IntoTarget for FromReady {
+    fn into_target(self) -> String {
+        format!("({})", self.build())
+    }
+}
+
+impl IntoStatement for FromReady {
+    fn into_statement(self) -> String {
+        self.build()
+    }
+}
@@ -0,0 +1,100 @@
+use std::fmt::Write;
+
+use crate::{
+    internal_macros::push_clause,
+    traits::IntoStatement,
+    types::show::{ChangesSince, ShowChangesData},
+};
+
+/// Builder for `SHOW CHANGES FOR TABLE` statements, created via
+/// [`QueryBuilder::show_changes`].
+///
+/// Reads the changefeed enabled by
+/// [`DefineTableBuilder::changefeed`](crate::builders::define::table::DefineTableBuilder::changefeed)
+/// or [`DefineDatabaseBuilder::changefeed`](crate::builders::define::database::DefineDatabaseBuilder::changefeed).
+///
+/// Pick the starting point with [`since`](Self::since) or
+/// [`since_versionstamp`](Self::since_versionstamp) to get a buildable
+/// [`ShowChangesReady`]; `SINCE` is mandatory:
+///
+/// ```compile_fail
+/// # use surrealex::QueryBuilder;
+/// QueryBuilder::show_changes("reading").build();
+/// ```
+///
+/// [`QueryBuilder::show_changes`]: crate::QueryBuilder::show_changes
+pub struct ShowChangesBuilder {
+    pub data: ShowChangesData,
+}
+
+impl ShowChangesBuilder {
+    /// Reads changes recorded at or after the given versionstamp.
+    ///
+    /// # Example
+    /// ```
+    /// # use surrealex::QueryBuilder;
+    /// let sql = QueryBuilder::show_changes("reading")
+    ///     .since_versionstamp(1)
+    ///     .limit(10)
+    ///     .build();
+    /// assert_eq!(sql, "SHOW CHANGES FOR TABLE reading SINCE 1 LIMIT 10");
+    /// ```
+    pub fn since_versionstamp(self, versionstamp: u64) -> ShowChangesReady {
+        ShowChangesReady { data: self.data }.since_versionstamp(versionstamp)
+    }
+
+    /// Reads changes recorded at or after the given ISO 8601 datetime,
+    /// rendered as a `d"..."` datetime literal.
+    pub fn since(self, datetime: &str) -> ShowChangesReady {
+        ShowChangesReady { data: self.data }.since(datetime)
+    }
+}
+
+/// A SHOW CHANGES statement with its `SINCE` clause set, ready to be built.
+pub struct ShowChangesReady {
+    pub data: ShowChangesData,
+}
+
+impl ShowChangesReady {
+    /// Reads changes recorded at or after the given versionstamp, replacing
+    /// the previous starting point.
+    pub fn since_versionstamp(mut self, versionstamp: u64) -> Self {
+        self.data.since = Some(ChangesSince::Versionstamp(versionstamp));
+        self
+    }
+
+    /// Reads changes recorded at or after the given ISO 8601 datetime,
+    /// replacing the previous starting point.
+    pub fn since(mut self, datetime: &str) -> Self {
+        self.data.since = Some(ChangesSince::Datetime(datetime.to_string()));
+        self
+    }
+
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.data.limit = Some(limit);
+        self
+    }
+
+    /// Builds the final SHOW CHANGES query string.
+    pub fn build(self) -> String {
+        let mut query = String::with_capacity(64);
+        let table = &self.data.table;
+        push_clause!(query, "SHOW CHANGES FOR TABLE {table}");
+
+        if let Some(ref since) = self.data.since {
+            push_clause!(query, "SINCE {since}");
+        }
+
+        if let Some(limit) = self.data.limit {
+            push_clause!(query, "LIMIT {limit}");
+        }
+
+        query
+    }
+}
+
+impl IntoStatement for ShowChangesReady {
+    fn into_statement(self) -> String {
+        self.build()
+    }
+}
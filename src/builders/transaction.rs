@@ -0,0 +1,113 @@
+use std::fmt::Write;
+
+use crate::{
+    builders::if_else::render_if,
+    enums::Condition,
+    internal_macros::push_clause,
+    traits::IntoStatement,
+    types::{
+        control::Throw,
+        if_else::{IfBody, IfBranch, IfData},
+        transaction::TransactionData,
+    },
+    versioning::if_else::VersionedIf,
+};
+
+/// Builder for `BEGIN TRANSACTION; ...; COMMIT TRANSACTION;` blocks, created
+/// via [`QueryBuilder::transaction`].
+///
+/// [`QueryBuilder::transaction`]: crate::QueryBuilder::transaction
+///
+/// # Example
+/// ```
+/// use surrealex::{QueryBuilder, SurrealV1};
+///
+/// let sql = QueryBuilder::transaction()
+///     .statement(QueryBuilder::update("account:one").set("balance", "balance - 100"))
+///     .statement(QueryBuilder::update("account:two").set("balance", "balance + 100"))
+///     .throw_if("account:one.balance < 0", "Insufficient funds")
+///     .build();
+/// assert_eq!(
+///     sql,
+///     "BEGIN TRANSACTION; UPDATE account:one SET balance = balance - 100; UPDATE account:two SET balance = balance + 100; IF account:one.balance < 0 { THROW \"Insufficient funds\" }; COMMIT TRANSACTION;"
+/// );
+///
+/// let sql = QueryBuilder::with_version(SurrealV1)
+///     .transaction()
+///     .throw_if("$balance < 0", "Insufficient funds")
+///     .build();
+/// assert_eq!(
+///     sql,
+///     "BEGIN TRANSACTION; IF $balance < 0 THEN (THROW \"Insufficient funds\") END; COMMIT TRANSACTION;"
+/// );
+/// ```
+pub struct Transaction<V> {
+    pub data: TransactionData,
+    pub(crate) renderer: V,
+}
+
+impl<V: VersionedIf> Transaction<V> {
+    /// Appends a statement to the transaction.
+    pub fn statement(mut self, statement: impl IntoStatement) -> Self {
+        self.data.statements.push(statement.into_statement());
+        self
+    }
+
+    /// Appends an `IF @condition { THROW @message }` statement, aborting the
+    /// transaction with `message` when `condition` holds. The guard uses the
+    /// configured version's IF syntax.
+    pub fn throw_if<T: Into<Condition>>(mut self, condition: T, message: &str) -> Self {
+        let data = IfData {
+            branches: vec![IfBranch {
+                condition: condition.into(),
+                body: IfBody::new(Throw::new(message)),
+            }],
+            ..Default::default()
+        };
+        let guard = render_if(&self.renderer, &data);
+        self.data.statements.push(guard);
+        self
+    }
+
+    /// Ends the block with `CANCEL TRANSACTION`, discarding every change.
+    pub fn cancel(mut self) -> Self {
+        self.data.cancel = true;
+        self
+    }
+
+    /// Builds the final transaction block.
+    pub fn build(self) -> String {
+        let mut query = self.block();
+        query.push(';');
+        query
+    }
+
+    /// Renders the block without the final `;`, so it can be joined with
+    /// other statements.
+    fn block(&self) -> String {
+        let mut query = String::with_capacity(256);
+        push_clause!(query, "BEGIN TRANSACTION;");
+
+        for statement in &self.data.statements {
+            push_clause!(query, "{statement};");
+        }
+
+        if self.data.cancel {
+            push_clause!(query, "CANCEL TRANSACTION");
+        } else {
+            push_clause!(query, "COMMIT TRANSACTION");
+        }
+
+        query
+    }
+}
+
+impl<V: VersionedIf> IntoStatement for Transaction<V> {
+    fn into_statement(self) -> String {
+        self.block()
+    }
+
+    fn is_multi_statement(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,214 @@
+use crate::{
+    enums::{Condition, ReturnClause},
+    internal_macros::push_clause,
+    traits::{IntoStatement, IntoTarget, IntoTimeout},
+    types::{
+        create::{ContentMode, SetField},
+        upsert::UpsertData,
+    },
+    versioning::upsert::VersionedUpsert,
+};
+use std::fmt::Write;
+
+pub struct UpsertBuilder<V> {
+    pub data: UpsertData,
+    pub(crate) renderer: V,
+}
+
+impl<V: VersionedUpsert> UpsertBuilder<V> {
+    /// Switches the statement from `UPSERT ...` to `UPSERT ONLY ...`.
+    ///
+    /// **Note:** SurrealDB expects a single-result `RETURN` when using `ONLY`.
+    /// The builder does not enforce this — the server will validate it at runtime.
+    pub fn only(mut self) -> Self {
+        self.data.only = true;
+        self
+    }
+
+    /// Sets the data-setting mode to `CONTENT @value`.
+    ///
+    /// This replaces any previous data-setting clause.
+    pub fn content(mut self, value: &str) -> Self {
+        self.data.content = Some(ContentMode::Content(value.to_string()));
+        self
+    }
+
+    /// Adds a `SET field = value` assignment.
+    ///
+    /// Multiple calls accumulate assignments. Any other previously set
+    /// data-setting clause is replaced by the `SET` clause.
+    ///
+    /// `value` is a raw SurrealQL expression, a script [`Param`](crate::types::script::Param)
+    /// or a statement builder, which is rendered as a parenthesized subquery.
+    ///
+    /// # Example
+    /// ```
+    /// # use surrealex::QueryBuilder;
+    /// let sql = QueryBuilder::upsert("person:tobie")
+    ///     .set("name", "'Tobie'")
+    ///     .build();
+    /// assert_eq!(sql, "UPSERT person:tobie SET name = 'Tobie'");
+    /// ```
+    pub fn set(mut self, field: &str, value: impl IntoTarget) -> Self {
+        let value = value.into_target();
+        let set_field = SetField {
+            field: field.to_string(),
+            value,
+        };
+        match &mut self.data.content {
+            Some(ContentMode::Set(fields)) => fields.push(set_field),
+            _ => self.data.content = Some(ContentMode::Set(vec![set_field])),
+        }
+        self
+    }
+
+    /// Adds a field to the `UNSET` clause.
+    ///
+    /// Multiple calls accumulate fields. Any other previously set
+    /// data-setting clause is replaced by the `UNSET` clause.
+    pub fn unset(mut self, field: &str) -> Self {
+        match &mut self.data.content {
+            Some(ContentMode::Unset(fields)) => fields.push(field.to_string()),
+            _ => self.data.content = Some(ContentMode::Unset(vec![field.to_string()])),
+        }
+        self
+    }
+
+    /// Sets the data-setting mode to `MERGE @value`.
+    ///
+    /// This replaces any previous data-setting clause.
+    pub fn merge(mut self, value: &str) -> Self {
+        self.data.content = Some(ContentMode::Merge(value.to_string()));
+        self
+    }
+
+    /// Sets the data-setting mode to `PATCH @value`.
+    ///
+    /// This replaces any previous data-setting clause.
+    pub fn patch(mut self, value: &str) -> Self {
+        self.data.content = Some(ContentMode::Patch(value.to_string()));
+        self
+    }
+
+    /// Sets the data-setting mode to `REPLACE @value`.
+    ///
+    /// This replaces any previous data-setting clause.
+    pub fn replace(mut self, value: &str) -> Self {
+        self.data.content = Some(ContentMode::Replace(value.to_string()));
+        self
+    }
+
+    /// Appends a WHERE condition. Multiple calls are joined with `AND`.
+    pub fn r#where<T: Into<Condition>>(mut self, condition: T) -> Self {
+        self.data.where_clause.push(condition.into());
+        self
+    }
+
+    /// Sets the RETURN clause to `RETURN NONE`.
+    pub fn return_none(mut self) -> Self {
+        self.data.return_clause = Some(ReturnClause::None);
+        self
+    }
+
+    /// Sets the RETURN clause to `RETURN BEFORE`.
+    pub fn return_before(mut self) -> Self {
+        self.data.return_clause = Some(ReturnClause::Before);
+        self
+    }
+
+    /// Sets the RETURN clause to `RETURN AFTER`.
+    pub fn return_after(mut self) -> Self {
+        self.data.return_clause = Some(ReturnClause::After);
+        self
+    }
+
+    /// Sets the RETURN clause to `RETURN DIFF`.
+    pub fn return_diff(mut self) -> Self {
+        self.data.return_clause = Some(ReturnClause::Diff);
+        self
+    }
+
+    /// Sets the RETURN clause to `RETURN <param1>, <param2>, ...`.
+    pub fn return_params<S: Into<String>>(mut self, params: Vec<S>) -> Self {
+        self.data.return_clause = Some(ReturnClause::Params(
+            params.into_iter().map(|s| s.into()).collect(),
+        ));
+        self
+    }
+
+    /// Sets the RETURN clause to `RETURN VALUE <field>`.
+    pub fn return_value(mut self, field: &str) -> Self {
+        self.data.return_clause = Some(ReturnClause::Value(field.to_string()));
+        self
+    }
+
+    /// Sets the TIMEOUT clause.
+    ///
+    /// Accepts either a raw SurrealQL duration string (e.g. `"500ms"`, `"2s"`, `"1m"`)
+    /// or a [`std::time::Duration`], which is automatically converted to SurrealQL syntax.
+    pub fn timeout(mut self, duration: impl IntoTimeout) -> Self {
+        self.data.timeout = Some(duration.into_timeout());
+        self
+    }
+
+    /// Adds the `PARALLEL` keyword to the statement.
+    pub fn parallel(mut self) -> Self {
+        self.data.parallel = true;
+        self
+    }
+
+    /// Builds the final UPSERT query string.
+    pub fn build(self) -> String {
+        let mut query = String::with_capacity(128);
+        let targets = &self.data.targets;
+
+        if self.data.only {
+            push_clause!(query, "UPSERT ONLY {targets}");
+        } else {
+            push_clause!(query, "UPSERT {targets}");
+        }
+
+        if let Some(ref content) = self.data.content {
+            let content = self.renderer.content(content);
+            push_clause!(query, "{content}");
+        }
+
+        if !self.data.where_clause.is_empty() {
+            let conditions: String = self
+                .data
+                .where_clause
+                .iter()
+                .map(|cond| cond.to_string())
+                .collect::<Vec<String>>()
+                .join(" AND ");
+
+            push_clause!(query, "WHERE {conditions}");
+        }
+
+        if let Some(ref rc) = self.data.return_clause {
+            push_clause!(query, "RETURN {rc}");
+        }
+
+        if let Some(ref duration) = self.data.timeout {
+            push_clause!(query, "TIMEOUT {duration}");
+        }
+
+        if self.data.parallel {
+            push_clause!(query, "PARALLEL");
+        }
+
+        query
+    }
+}
+
+impl<V: VersionedUpsert> IntoStatement for UpsertBuilder<V> {
+    fn into_statement(self) -> String {
+        self.build()
+    }
+}
+
+impl<V: VersionedUpsert> IntoTarget for UpsertBuilder<V> {
+    fn into_target(self) -> String {
+        format!("({})", self.build())
+    }
+}
@@ -0,0 +1,168 @@
+use std::fmt::Write;
+
+use crate::{
+    internal_macros::push_clause,
+    traits::IntoStatement,
+    types::utility::{OptionData, RebuildIndexData, SleepData, UseData},
+};
+
+/// Builder for `USE NS @ns DB @db` statements, created via
+/// [`QueryBuilder::r#use`].
+///
+/// Pick the namespace and/or database to get a buildable [`UseReady`]; a bare
+/// `USE` can't be built:
+///
+/// ```compile_fail
+/// # use surrealex::QueryBuilder;
+/// QueryBuilder::r#use().build();
+/// ```
+///
+/// [`QueryBuilder::r#use`]: crate::QueryBuilder::use
+///
+/// # Example
+/// ```
+/// # use surrealex::QueryBuilder;
+/// let sql = QueryBuilder::r#use().ns("acme").db("app").build();
+/// assert_eq!(sql, "USE NS acme DB app");
+/// ```
+pub struct UseBuilder {
+    pub data: UseData,
+}
+
+impl UseBuilder {
+    /// Sets the namespace to switch to.
+    pub fn ns(self, namespace: &str) -> UseReady {
+        UseReady { data: self.data }.ns(namespace)
+    }
+
+    /// Sets the database to switch to.
+    pub fn db(self, database: &str) -> UseReady {
+        UseReady { data: self.data }.db(database)
+    }
+}
+
+/// A USE statement with a namespace and/or database set, ready to be built.
+pub struct UseReady {
+    pub data: UseData,
+}
+
+impl UseReady {
+    /// Sets the namespace to switch to.
+    pub fn ns(mut self, namespace: &str) -> Self {
+        self.data.namespace = Some(namespace.to_string());
+        self
+    }
+
+    /// Sets the database to switch to.
+    pub fn db(mut self, database: &str) -> Self {
+        self.data.database = Some(database.to_string());
+        self
+    }
+
+    /// Builds the final USE query string.
+    pub fn build(self) -> String {
+        let mut query = String::with_capacity(32);
+        push_clause!(query, "USE");
+
+        if let Some(ref namespace) = self.data.namespace {
+            push_clause!(query, "NS {namespace}");
+        }
+
+        if let Some(ref database) = self.data.database {
+            push_clause!(query, "DB {database}");
+        }
+
+        query
+    }
+}
+
+impl IntoStatement for UseReady {
+    fn into_statement(self) -> String {
+        self.build()
+    }
+}
+
+/// Builder for `SLEEP @duration` statements, created via
+/// [`QueryBuilder::sleep`].
+///
+/// [`QueryBuilder::sleep`]: crate::QueryBuilder::sleep
+pub struct SleepBuilder {
+    pub data: SleepData,
+}
+
+impl SleepBuilder {
+    /// Builds the final SLEEP query string.
+    pub fn build(self) -> String {
+        format!("SLEEP {}", self.data.duration)
+    }
+}
+
+impl IntoStatement for SleepBuilder {
+    fn into_statement(self) -> String {
+        self.build()
+    }
+}
+
+/// Builder for `OPTION @option [= false]` statements, created via
+/// [`QueryBuilder::option`].
+///
+/// [`QueryBuilder::option`]: crate::QueryBuilder::option
+pub struct OptionBuilder {
+    pub data: OptionData,
+}
+
+impl OptionBuilder {
+    /// Builds the final OPTION query string.
+    pub fn build(self) -> String {
+        let option = &self.data.option;
+        if self.data.enabled {
+            format!("OPTION {option}")
+        } else {
+            format!("OPTION {option} = false")
+        }
+    }
+}
+
+impl IntoStatement for OptionBuilder {
+    fn into_statement(self) -> String {
+        self.build()
+    }
+}
+
+/// Builder for `REBUILD INDEX @name ON @table` statements, created via
+/// [`QueryBuilder::rebuild_index`].
+///
+/// [`QueryBuilder::rebuild_index`]: crate::QueryBuilder::rebuild_index
+pub struct RebuildIndexBuilder {
+    pub data: RebuildIndexData,
+}
+
+impl RebuildIndexBuilder {
+    /// Adds `IF EXISTS`, turning the statement into a no-op for missing indexes.
+    pub fn if_exists(mut self) -> Self {
+        self.data.if_exists = true;
+        self
+    }
+
+    /// Builds the final REBUILD INDEX query string.
+    pub fn build(self) -> String {
+        let mut query = String::with_capacity(64);
+        push_clause!(query, "REBUILD INDEX");
+
+        if self.data.if_exists {
+            push_clause!(query, "IF EXISTS");
+        }
+
+        let name = &self.data.name;
+        let table = &self.data.table;
+        push_clause!(query, "{name} ON {table}");
+
+        query
+    }
+}
+
+impl IntoStatement for RebuildIndexBuilder {
+    fn into_statement(self) -> String {
+        self.build()
+    }
+}
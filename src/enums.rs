@@ -84,6 +84,28 @@ impl Display for Direction {
     }
 }
 
+/// The level a resource is defined on (`ON ROOT | NAMESPACE | DATABASE`).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum Base {
+    /// `ROOT`
+    Root,
+    /// `NAMESPACE`
+    Namespace,
+    /// `DATABASE`
+    #[default]
+    Database,
+}
+
+impl Display for Base {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Base::Root => write!(f, "ROOT"),
+            Base::Namespace => write!(f, "NAMESPACE"),
+            Base::Database => write!(f, "DATABASE"),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum Condition {
     /// A simple, raw condition string (e.g., "price > 50").
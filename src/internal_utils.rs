@@ -0,0 +1,6 @@
+/// Renders `value` as a double-quoted SurrealQL string literal, escaping
+/// backslashes and double quotes.
+pub(crate) fn quote_str(value: &str) -> String {
+    let escaped = value.replace('\\', "\\\\").replace('"', "\\\"");
+    format!("\"{escaped}\"")
+}
@@ -5,24 +5,74 @@ pub mod macros;
 
 pub mod builders;
 pub(crate) mod internal_macros;
+pub(crate) mod internal_utils;
 pub mod traits;
 pub mod types;
 pub mod versioning;
 
-pub use crate::versioning::{SurrealV1, SurrealV2};
+pub use crate::versioning::{SurrealV1, SurrealV2, SurrealV3};
+
+use std::marker::PhantomData;
 
 use crate::{
     builders::{
-        create::CreateBuilder, delete::DeleteBuilder, insert::InsertBuilder, select::SelectBuilder,
+        access::AccessBuilder,
+        alter::AlterTableBuilder,
+        create::CreateBuilder,
+        define::{
+            access::DefineAccessBuilder, analyzer::DefineAnalyzerBuilder,
+            database::DefineDatabaseBuilder, event::DefineEventBuilder, field::DefineFieldBuilder,
+            function::DefineFunctionBuilder, index::DefineIndexBuilder,
+            namespace::DefineNamespaceBuilder, param::DefineParamBuilder,
+            table::DefineTableBuilder, user::DefineUserBuilder,
+        },
+        delete::DeleteBuilder,
+        for_loop::ForLoop,
+        if_else::If,
+        info::InfoBuilder,
+        insert::InsertBuilder,
+        kill::KillBuilder,
+        live::LiveBuilder,
+        relate::RelateBuilder,
+        remove::RemoveBuilder,
+        script::Script,
+        select::{FromReady, SelectBuilder, SelectValueBuilder},
+        show::ShowChangesBuilder,
+        transaction::Transaction,
+        update::UpdateBuilder,
+        upsert::UpsertBuilder,
+        utility::{OptionBuilder, RebuildIndexBuilder, SleepBuilder, UseBuilder},
     },
-    enums::SelectionFields,
+    enums::{Condition, SelectionFields},
+    internal_utils::quote_str,
+    traits::{IntoStatement, IntoTarget, IntoTimeout, ToSelectField},
     types::{
+        alter::AlterTableData,
         create::CreateData,
+        define::{
+            access::DefineAccessData, analyzer::DefineAnalyzerData, database::DefineDatabaseData,
+            event::DefineEventData, field::DefineFieldData, function::DefineFunctionData,
+            index::DefineIndexData, namespace::DefineNamespaceData, param::DefineParamData,
+            table::DefineTableData, user::DefineUserData,
+        },
         delete::DeleteData,
+        for_loop::ForLoopData,
+        if_else::{IfBody, IfBranch, IfData},
         insert::InsertData,
-        select::{SelectData, SelectField},
+        kill::KillData,
+        live::LiveData,
+        relate::RelateData,
+        select::{GraphTraversalParams, SelectData, SelectField},
+        show::ShowChangesData,
+        transaction::TransactionData,
+        update::UpdateData,
+        upsert::UpsertData,
+        utility::{OptionData, QueryOption, RebuildIndexData, SleepData, UseData},
+    },
+    versioning::{
+        SinceV2, SinceV3, access::VersionedAccess, if_else::VersionedIf, select::VersionedSelect,
+        update::VersionedUpdate, upsert::VersionedUpsert,
     },
-    versioning::select::VersionedSelect,
 };
 
 #[derive(Debug)]
@@ -46,17 +96,80 @@ impl QueryBuilder {
         }
     }
 
-    pub fn delete(targets: &str) -> DeleteBuilder {
+    /// Starts a `SELECT VALUE @expr` statement, returning bare values instead
+    /// of objects. Any alias on `expr` is dropped.
+    ///
+    /// ```rust
+    /// use surrealex::QueryBuilder;
+    ///
+    /// let sql = QueryBuilder::select_value("name").from("user").build();
+    /// assert_eq!(sql, "SELECT VALUE name FROM user");
+    /// ```
+    pub fn select_value(expr: impl ToSelectField) -> SelectValueBuilder<SurrealV2> {
+        let field = expr.to_select_field();
+        let data = SelectData {
+            fields: vec![SelectField {
+                name: field.name,
+                alias: None,
+            }],
+            value: true,
+            ..Default::default()
+        };
+        SelectValueBuilder {
+            data,
+            renderer: SurrealV2,
+        }
+    }
+    /// Starts a `SELECT VALUE (@subquery)` statement, returning the subquery
+    /// results as bare values.
+    ///
+    /// ```rust
+    /// use surrealex::QueryBuilder;
+    ///
+    /// let sql = QueryBuilder::select_value_subquery(QueryBuilder::select_value("name").from("user"))
+    ///     .from("ONLY $input")
+    ///     .build();
+    /// assert_eq!(sql, "SELECT VALUE (SELECT VALUE name FROM user) FROM ONLY $input");
+    /// ```
+    pub fn select_value_subquery(subquery: FromReady) -> SelectValueBuilder<SurrealV2> {
+        Self::select_value(subquery)
+    }
+
+    /// Starts a `SELECT VALUE @traversal` statement, returning the traversed
+    /// records as bare values. Any alias on the traversal is dropped.
+    ///
+    /// ```rust
+    /// use surrealex::QueryBuilder;
+    /// use surrealex::types::select::GraphTraversalParams;
+    ///
+    /// let sql = QueryBuilder::select_value_traverse(GraphTraversalParams::start_out("wrote").step_out("book"))
+    ///     .from("person")
+    ///     .build();
+    /// assert_eq!(sql, "SELECT VALUE ->wrote->book.* FROM person");
+    /// ```
+    pub fn select_value_traverse(params: GraphTraversalParams) -> SelectValueBuilder<SurrealV2> {
+        let data = SelectData {
+            value: true,
+            ..Default::default()
+        };
+        SelectValueBuilder {
+            data,
+            renderer: SurrealV2,
+        }
+        .graph_traverse(params)
+    }
+
+    pub fn delete(targets: impl IntoTarget) -> DeleteBuilder {
         let data = DeleteData {
-            targets: targets.to_string(),
+            targets: targets.into_target(),
             ..Default::default()
         };
         DeleteBuilder { data }
     }
 
-    pub fn create(targets: &str) -> CreateBuilder {
+    pub fn create(targets: impl IntoTarget) -> CreateBuilder {
         let data = CreateData {
-            targets: targets.to_string(),
+            targets: targets.into_target(),
             ..Default::default()
         };
         CreateBuilder { data }
@@ -70,10 +183,327 @@ impl QueryBuilder {
         InsertBuilder { data }
     }
 
+    pub fn update(targets: impl IntoTarget) -> UpdateBuilder<SurrealV2> {
+        let data = UpdateData {
+            targets: targets.into_target(),
+            ..Default::default()
+        };
+        UpdateBuilder {
+            data,
+            renderer: SurrealV2,
+        }
+    }
+
+    pub fn relate(from: impl IntoTarget, edge: &str, to: impl IntoTarget) -> RelateBuilder {
+        let data = RelateData {
+            from: from.into_target(),
+            edge: edge.to_string(),
+            to: to.into_target(),
+            ..Default::default()
+        };
+        RelateBuilder { data }
+    }
+
+    pub fn upsert(targets: impl IntoTarget) -> UpsertBuilder<SurrealV2> {
+        let data = UpsertData {
+            targets: targets.into_target(),
+            ..Default::default()
+        };
+        UpsertBuilder {
+            data,
+            renderer: SurrealV2,
+        }
+    }
+
+    pub fn define_table(name: &str) -> DefineTableBuilder<SurrealV2> {
+        let data = DefineTableData {
+            name: name.to_string(),
+            ..Default::default()
+        };
+        DefineTableBuilder {
+            data,
+            version: PhantomData,
+        }
+    }
+
+    pub fn define_field(name: &str, table: &str) -> DefineFieldBuilder<SurrealV2> {
+        let data = DefineFieldData {
+            name: name.to_string(),
+            table: table.to_string(),
+            ..Default::default()
+        };
+        DefineFieldBuilder {
+            data,
+            version: PhantomData,
+        }
+    }
+
+    pub fn define_index(name: &str, table: &str) -> DefineIndexBuilder<SurrealV2> {
+        let data = DefineIndexData {
+            name: name.to_string(),
+            table: table.to_string(),
+            ..Default::default()
+        };
+        DefineIndexBuilder {
+            data,
+            version: PhantomData,
+        }
+    }
+
+    pub fn define_event(name: &str, table: &str) -> DefineEventBuilder<SurrealV2> {
+        let data = DefineEventData {
+            name: name.to_string(),
+            table: table.to_string(),
+            ..Default::default()
+        };
+        DefineEventBuilder {
+            data,
+            version: PhantomData,
+        }
+    }
+
+    pub fn define_function(name: &str) -> DefineFunctionBuilder<SurrealV2> {
+        let data = DefineFunctionData {
+            name: name.to_string(),
+            ..Default::default()
+        };
+        DefineFunctionBuilder {
+            data,
+            version: PhantomData,
+        }
+    }
+
+    pub fn define_access(name: &str) -> DefineAccessBuilder<SurrealV2> {
+        let data = DefineAccessData {
+            name: name.to_string(),
+            ..Default::default()
+        };
+        DefineAccessBuilder {
+            data,
+            renderer: SurrealV2,
+        }
+    }
+
+    pub fn define_user(name: &str) -> DefineUserBuilder<SurrealV2> {
+        let data = DefineUserData {
+            name: name.to_string(),
+            ..Default::default()
+        };
+        DefineUserBuilder {
+            data,
+            version: PhantomData,
+        }
+    }
+
+    pub fn define_analyzer(name: &str) -> DefineAnalyzerBuilder<SurrealV2> {
+        let data = DefineAnalyzerData {
+            name: name.to_string(),
+            ..Default::default()
+        };
+        DefineAnalyzerBuilder {
+            data,
+            version: PhantomData,
+        }
+    }
+
+    pub fn define_namespace(name: &str) -> DefineNamespaceBuilder<SurrealV2> {
+        let data = DefineNamespaceData {
+            name: name.to_string(),
+            ..Default::default()
+        };
+        DefineNamespaceBuilder {
+            data,
+            version: PhantomData,
+        }
+    }
+
+    pub fn define_database(name: &str) -> DefineDatabaseBuilder<SurrealV2> {
+        let data = DefineDatabaseData {
+            name: name.to_string(),
+            ..Default::default()
+        };
+        DefineDatabaseBuilder {
+            data,
+            version: PhantomData,
+        }
+    }
+
+    /// Starts a `DEFINE PARAM $name VALUE @value` statement. A leading `$` in
+    /// `name` is optional, and statement builders are rendered as
+    /// parenthesized subqueries.
+    ///
+    /// ```rust
+    /// use surrealex::QueryBuilder;
+    ///
+    /// let sql = QueryBuilder::define_param("$endpoint", "\"https://api.example.com\"")
+    ///     .permissions_none()
+    ///     .build();
+    /// assert_eq!(
+    ///     sql,
+    ///     "DEFINE PARAM $endpoint VALUE \"https://api.example.com\" PERMISSIONS NONE"
+    /// );
+    /// ```
+    pub fn define_param(name: &str, value: impl IntoTarget) -> DefineParamBuilder<SurrealV2> {
+        let data = DefineParamData {
+            name: name.trim_start_matches('$').to_string(),
+            value: value.into_target(),
+            ..Default::default()
+        };
+        DefineParamBuilder {
+            data,
+            version: PhantomData,
+        }
+    }
+
+    pub fn live(fields: SelectionFields) -> LiveBuilder {
+        let data = LiveData {
+            fields: match fields {
+                SelectionFields::All => vec![SelectField {
+                    name: "*".to_string(),
+                    alias: None,
+                }],
+                SelectionFields::Fields(select_fields) => select_fields,
+            },
+            ..Default::default()
+        };
+        LiveBuilder { data }
+    }
+
+    /// Starts a `LIVE SELECT DIFF` statement, streaming changes as JSON Patch
+    /// operations instead of full records.
+    pub fn live_diff() -> LiveBuilder {
+        let data = LiveData {
+            diff: true,
+            ..Default::default()
+        };
+        LiveBuilder { data }
+    }
+
+    /// Starts a `KILL` statement for the given live query.
+    ///
+    /// Parameters (starting with `$`) are rendered as-is; anything else is
+    /// treated as a live query UUID and rendered as a quoted string.
+    pub fn kill(live_query: &str) -> KillBuilder {
+        let target = if live_query.starts_with('$') {
+            live_query.to_string()
+        } else {
+            quote_str(live_query)
+        };
+        KillBuilder {
+            data: KillData { target },
+        }
+    }
+
+    /// Starts an `IF @condition { @body }` statement. Chain
+    /// [`else_if`](If::else_if) and [`r#else`](If::else) for further branches.
+    pub fn r#if<T: Into<Condition>>(condition: T, body: impl IntoStatement) -> If<SurrealV2> {
+        let data = IfData {
+            branches: vec![IfBranch {
+                condition: condition.into(),
+                body: IfBody::new(body),
+            }],
+            ..Default::default()
+        };
+        If {
+            data,
+            renderer: SurrealV2,
+        }
+    }
+
+    /// Starts a `FOR $item IN @iterable { ... }` loop. A leading `$` in `item`
+    /// is optional.
+    pub fn r#for(item: &str, iterable: impl IntoTarget) -> ForLoop {
+        let data = ForLoopData {
+            item: item.trim_start_matches('$').to_string(),
+            iterable: iterable.into_target(),
+            ..Default::default()
+        };
+        ForLoop { data }
+    }
+
+    /// Starts an empty multi-statement [`Script`].
+    pub fn script() -> Script {
+        Script::default()
+    }
+
+    /// Starts a `BEGIN TRANSACTION; ...; COMMIT TRANSACTION;` block.
+    pub fn transaction() -> Transaction<SurrealV2> {
+        Transaction {
+            data: TransactionData::default(),
+            renderer: SurrealV2,
+        }
+    }
+
+    pub fn show_changes(table: &str) -> ShowChangesBuilder {
+        let data = ShowChangesData {
+            table: table.to_string(),
+            ..Default::default()
+        };
+        ShowChangesBuilder { data }
+    }
+
+    /// Starts a `USE` statement. Pick the namespace and database with
+    /// [`ns`](UseBuilder::ns) and [`db`](UseBuilder::db).
+    pub fn r#use() -> UseBuilder {
+        UseBuilder {
+            data: UseData::default(),
+        }
+    }
+
+    /// Creates a `SLEEP @duration` statement.
+    pub fn sleep(duration: impl IntoTimeout) -> SleepBuilder {
+        let data = SleepData {
+            duration: duration.into_timeout(),
+        };
+        SleepBuilder { data }
+    }
+
+    /// Creates an `OPTION @option` statement, or `OPTION @option = false` when
+    /// `enabled` is `false`.
+    pub fn option(option: QueryOption, enabled: bool) -> OptionBuilder {
+        let data = OptionData { option, enabled };
+        OptionBuilder { data }
+    }
+
+    /// Creates a `REBUILD INDEX @name ON @table` statement.
+    pub fn rebuild_index(name: &str, table: &str) -> RebuildIndexBuilder {
+        let data = RebuildIndexData {
+            name: name.to_string(),
+            table: table.to_string(),
+            ..Default::default()
+        };
+        RebuildIndexBuilder { data }
+    }
+
+    /// Starts an `ACCESS` statement managing the bearer grants of an access
+    /// method.
+    pub fn access(name: &str) -> AccessBuilder {
+        AccessBuilder {
+            name: name.to_string(),
+            base: None,
+        }
+    }
+
+    /// Starts an INFO statement. Pick the scope to inspect with one of the
+    /// [`InfoBuilder`] methods.
+    pub fn info() -> InfoBuilder<SurrealV2> {
+        InfoBuilder {
+            version: PhantomData,
+        }
+    }
+
+    /// Starts a REMOVE statement. Pick the resource to remove with one of the
+    /// [`RemoveBuilder`] methods.
+    pub fn remove() -> RemoveBuilder<SurrealV2> {
+        RemoveBuilder {
+            version: PhantomData,
+        }
+    }
+
     /// Create a version-aware query builder.
     ///
     /// Use this to target a specific SurrealDB version for query rendering.
-    /// The version is expressed as a zero-sized type (`SurrealV1` or `SurrealV2`),
+    /// The version is expressed as a zero-sized type (`SurrealV1`, `SurrealV2` or `SurrealV3`),
     /// enabling fully monomorphized, zero-cost dispatch at compile time.
     ///
     /// ```rust
@@ -115,20 +545,195 @@ impl<V: VersionedSelect> VersionedQueryBuilder<V> {
             renderer: self.renderer,
         }
     }
+
+    /// Starts a `SELECT VALUE @expr` statement, returning bare values instead
+    /// of objects. Any alias on `expr` is dropped.
+    ///
+    /// ```rust
+    /// use surrealex::QueryBuilder;
+    ///
+    /// let sql = QueryBuilder::select_value("name").from("user").build();
+    /// assert_eq!(sql, "SELECT VALUE name FROM user");
+    /// ```
+    pub fn select_value(self, expr: impl ToSelectField) -> SelectValueBuilder<V> {
+        let field = expr.to_select_field();
+        let data = SelectData {
+            fields: vec![SelectField {
+                name: field.name,
+                alias: None,
+            }],
+            value: true,
+            ..Default::default()
+        };
+        SelectValueBuilder {
+            data,
+            renderer: self.renderer,
+        }
+    }
+
+    /// Starts a `SELECT VALUE (@subquery)` statement, returning the subquery
+    /// results as bare values.
+    ///
+    /// ```rust
+    /// use surrealex::{QueryBuilder, SurrealV1};
+    ///
+    /// let sql = QueryBuilder::with_version(SurrealV1)
+    ///     .select_value_subquery(QueryBuilder::select_value("name").from("user"))
+    ///     .from("ONLY $input")
+    ///     .build();
+    /// assert_eq!(sql, "SELECT VALUE (SELECT VALUE name FROM user) FROM ONLY $input");
+    /// ```
+    pub fn select_value_subquery(self, subquery: FromReady) -> SelectValueBuilder<V> {
+        self.select_value(subquery)
+    }
+
+    /// Starts a `SELECT VALUE @traversal` statement, returning the traversed
+    /// records as bare values. Any alias on the traversal is dropped.
+    ///
+    /// ```rust
+    /// use surrealex::{QueryBuilder, SurrealV1};
+    /// use surrealex::types::select::GraphTraversalParams;
+    ///
+    /// let sql = QueryBuilder::with_version(SurrealV1)
+    ///     .select_value_traverse(GraphTraversalParams::start_out("wrote").step_out("book"))
+    ///     .from("person")
+    ///     .build();
+    /// assert_eq!(sql, "SELECT VALUE ->wrote->book.* FROM person");
+    /// ```
+    pub fn select_value_traverse(self, params: GraphTraversalParams) -> SelectValueBuilder<V> {
+        let data = SelectData {
+            value: true,
+            ..Default::default()
+        };
+        SelectValueBuilder {
+            data,
+            renderer: self.renderer,
+        }
+        .graph_traverse(params)
+    }
+}
+
+impl<V: VersionedUpdate> VersionedQueryBuilder<V> {
+    pub fn update(self, targets: impl IntoTarget) -> UpdateBuilder<V> {
+        let data = UpdateData {
+            targets: targets.into_target(),
+            ..Default::default()
+        };
+        UpdateBuilder {
+            data,
+            renderer: self.renderer,
+        }
+    }
+}
+
+impl<V: VersionedUpsert> VersionedQueryBuilder<V> {
+    /// Starts an UPSERT statement.
+    ///
+    /// Only available when targeting SurrealDB 2.0 or newer.
+    ///
+    /// ```compile_fail
+    /// use surrealex::{QueryBuilder, SurrealV1};
+    ///
+    /// QueryBuilder::with_version(SurrealV1).upsert("person");
+    /// ```
+    pub fn upsert(self, targets: impl IntoTarget) -> UpsertBuilder<V> {
+        let data = UpsertData {
+            targets: targets.into_target(),
+            ..Default::default()
+        };
+        UpsertBuilder {
+            data,
+            renderer: self.renderer,
+        }
+    }
+}
+
+impl<V: VersionedAccess> VersionedQueryBuilder<V> {
+    pub fn define_access(self, name: &str) -> DefineAccessBuilder<V> {
+        let data = DefineAccessData {
+            name: name.to_string(),
+            ..Default::default()
+        };
+        DefineAccessBuilder {
+            data,
+            renderer: self.renderer,
+        }
+    }
+}
+
+impl<V: SinceV2> VersionedQueryBuilder<V> {
+    /// Creates a `REBUILD INDEX @name ON @table` statement.
+    ///
+    /// Only available when targeting SurrealDB 2.0 or newer.
+    ///
+    /// ```compile_fail
+    /// use surrealex::{QueryBuilder, SurrealV1};
+    ///
+    /// QueryBuilder::with_version(SurrealV1).rebuild_index("idx", "post");
+    /// ```
+    pub fn rebuild_index(self, name: &str, table: &str) -> RebuildIndexBuilder {
+        QueryBuilder::rebuild_index(name, table)
+    }
+
+    /// Starts an `ACCESS` statement managing the bearer grants of an access
+    /// method.
+    ///
+    /// Only available when targeting SurrealDB 2.0 or newer.
+    pub fn access(self, name: &str) -> AccessBuilder {
+        QueryBuilder::access(name)
+    }
+}
+
+impl<V: SinceV3> VersionedQueryBuilder<V> {
+    /// Starts an ALTER TABLE statement, changing properties of an existing
+    /// table without redefining it.
+    ///
+    /// Only available when targeting SurrealDB 3.0 or newer.
+    pub fn alter_table(self, name: &str) -> AlterTableBuilder {
+        let data = AlterTableData {
+            name: name.to_string(),
+            ..Default::default()
+        };
+        AlterTableBuilder { data }
+    }
+}
+
+impl<V: VersionedIf> VersionedQueryBuilder<V> {
+    pub fn r#if<T: Into<Condition>>(self, condition: T, body: impl IntoStatement) -> If<V> {
+        let data = IfData {
+            branches: vec![IfBranch {
+                condition: condition.into(),
+                body: IfBody::new(body),
+            }],
+            ..Default::default()
+        };
+        If {
+            data,
+            renderer: self.renderer,
+        }
+    }
+
+    /// Starts a `BEGIN TRANSACTION; ...; COMMIT TRANSACTION;` block.
+    pub fn transaction(self) -> Transaction<V> {
+        Transaction {
+            data: TransactionData::default(),
+            renderer: self.renderer,
+        }
+    }
 }
 
 impl<V> VersionedQueryBuilder<V> {
-    pub fn delete(self, targets: &str) -> DeleteBuilder {
+    pub fn delete(self, targets: impl IntoTarget) -> DeleteBuilder {
         let data = DeleteData {
-            targets: targets.to_string(),
+            targets: targets.into_target(),
             ..Default::default()
         };
         DeleteBuilder { data }
     }
 
-    pub fn create(self, targets: &str) -> CreateBuilder {
+    pub fn create(self, targets: impl IntoTarget) -> CreateBuilder {
         let data = CreateData {
-            targets: targets.to_string(),
+            targets: targets.into_target(),
             ..Default::default()
         };
         CreateBuilder { data }
@@ -141,4 +746,207 @@ impl<V> VersionedQueryBuilder<V> {
         };
         InsertBuilder { data }
     }
+    pub fn relate(self, from: impl IntoTarget, edge: &str, to: impl IntoTarget) -> RelateBuilder {
+        let data = RelateData {
+            from: from.into_target(),
+            edge: edge.to_string(),
+            to: to.into_target(),
+            ..Default::default()
+        };
+        RelateBuilder { data }
+    }
+    pub fn define_table(self, name: &str) -> DefineTableBuilder<V> {
+        let data = DefineTableData {
+            name: name.to_string(),
+            ..Default::default()
+        };
+        DefineTableBuilder {
+            data,
+            version: PhantomData,
+        }
+    }
+    pub fn define_field(self, name: &str, table: &str) -> DefineFieldBuilder<V> {
+        let data = DefineFieldData {
+            name: name.to_string(),
+            table: table.to_string(),
+            ..Default::default()
+        };
+        DefineFieldBuilder {
+            data,
+            version: PhantomData,
+        }
+    }
+    pub fn define_index(self, name: &str, table: &str) -> DefineIndexBuilder<V> {
+        let data = DefineIndexData {
+            name: name.to_string(),
+            table: table.to_string(),
+            ..Default::default()
+        };
+        DefineIndexBuilder {
+            data,
+            version: PhantomData,
+        }
+    }
+    pub fn define_event(self, name: &str, table: &str) -> DefineEventBuilder<V> {
+        let data = DefineEventData {
+            name: name.to_string(),
+            table: table.to_string(),
+            ..Default::default()
+        };
+        DefineEventBuilder {
+            data,
+            version: PhantomData,
+        }
+    }
+    pub fn define_function(self, name: &str) -> DefineFunctionBuilder<V> {
+        let data = DefineFunctionData {
+            name: name.to_string(),
+            ..Default::default()
+        };
+        DefineFunctionBuilder {
+            data,
+            version: PhantomData,
+        }
+    }
+    pub fn define_user(self, name: &str) -> DefineUserBuilder<V> {
+        let data = DefineUserData {
+            name: name.to_string(),
+            ..Default::default()
+        };
+        DefineUserBuilder {
+            data,
+            version: PhantomData,
+        }
+    }
+    pub fn remove(self) -> RemoveBuilder<V> {
+        RemoveBuilder {
+            version: PhantomData,
+        }
+    }
+
+    pub fn define_analyzer(self, name: &str) -> DefineAnalyzerBuilder<V> {
+        let data = DefineAnalyzerData {
+            name: name.to_string(),
+            ..Default::default()
+        };
+        DefineAnalyzerBuilder {
+            data,
+            version: PhantomData,
+        }
+    }
+
+    pub fn define_namespace(self, name: &str) -> DefineNamespaceBuilder<V> {
+        let data = DefineNamespaceData {
+            name: name.to_string(),
+            ..Default::default()
+        };
+        DefineNamespaceBuilder {
+            data,
+            version: PhantomData,
+        }
+    }
+
+    pub fn define_database(self, name: &str) -> DefineDatabaseBuilder<V> {
+        let data = DefineDatabaseData {
+            name: name.to_string(),
+            ..Default::default()
+        };
+        DefineDatabaseBuilder {
+            data,
+            version: PhantomData,
+        }
+    }
+
+    pub fn define_param(self, name: &str, value: impl IntoTarget) -> DefineParamBuilder<V> {
+        let data = DefineParamData {
+            name: name.trim_start_matches('$').to_string(),
+            value: value.into_target(),
+            ..Default::default()
+        };
+        DefineParamBuilder {
+            data,
+            version: PhantomData,
+        }
+    }
+    pub fn info(self) -> InfoBuilder<V> {
+        InfoBuilder {
+            version: PhantomData,
+        }
+    }
+
+    pub fn live(self, fields: SelectionFields) -> LiveBuilder {
+        let data = LiveData {
+            fields: match fields {
+                SelectionFields::All => vec![SelectField {
+                    name: "*".to_string(),
+                    alias: None,
+                }],
+                SelectionFields::Fields(select_fields) => select_fields,
+            },
+            ..Default::default()
+        };
+        LiveBuilder { data }
+    }
+
+    /// Starts a `LIVE SELECT DIFF` statement, streaming changes as JSON Patch
+    /// operations instead of full records.
+    pub fn live_diff(self) -> LiveBuilder {
+        let data = LiveData {
+            diff: true,
+            ..Default::default()
+        };
+        LiveBuilder { data }
+    }
+
+    /// Starts a `KILL` statement for the given live query.
+    ///
+    /// Parameters (starting with `$`) are rendered as-is; anything else is
+    /// treated as a live query UUID and rendered as a quoted string.
+    pub fn kill(self, live_query: &str) -> KillBuilder {
+        let target = if live_query.starts_with('$') {
+            live_query.to_string()
+        } else {
+            quote_str(live_query)
+        };
+        KillBuilder {
+            data: KillData { target },
+        }
+    }
+    pub fn script(self) -> Script {
+        Script::default()
+    }
+
+    pub fn r#for(self, item: &str, iterable: impl IntoTarget) -> ForLoop {
+        let data = ForLoopData {
+            item: item.trim_start_matches('$').to_string(),
+            iterable: iterable.into_target(),
+            ..Default::default()
+        };
+        ForLoop { data }
+    }
+
+    pub fn show_changes(self, table: &str) -> ShowChangesBuilder {
+        let data = ShowChangesData {
+            table: table.to_string(),
+            ..Default::default()
+        };
+        ShowChangesBuilder { data }
+    }
+
+    /// Starts a `USE` statement. Pick the namespace and database with
+    /// [`ns`](UseBuilder::ns) and [`db`](UseBuilder::db).
+    pub fn r#use(self) -> UseBuilder {
+        QueryBuilder::r#use()
+    }
+
+    /// Creates a `SLEEP @duration` statement.
+    pub fn sleep(self, duration: impl IntoTimeout) -> SleepBuilder {
+        QueryBuilder::sleep(duration)
+    }
+
+    /// Creates an `OPTION @option` statement, or `OPTION @option = false` when
+    /// `enabled` is `false`.
+    pub fn option(self, option: QueryOption, enabled: bool) -> OptionBuilder {
+        QueryBuilder::option(option, enabled)
+    }
 }
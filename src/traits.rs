@@ -24,6 +24,106 @@ impl ToSelectField for (&str, &str) {
     }
 }
 
+/// Trait for values that can be used as the subject of a statement or as a
+/// value expression, such as either side of a `RELATE` statement or the value
+/// of a `LET` binding.
+///
+/// Implemented for:
+/// - `&str` / `String` — passed through as-is (e.g. `"person:tobie"`, `"$person"`).
+/// - `Vec<S>` — rendered as an array of record ids (e.g. `[person:tobie, person:jaime]`).
+/// - [`FromReady`](crate::builders::select::FromReady) and the write statement
+///   builders — rendered as a parenthesized subquery.
+///
+/// # Examples
+///
+/// ```
+/// # use surrealex::QueryBuilder;
+/// let sql = QueryBuilder::relate(
+///     vec!["person:tobie", "person:jaime"],
+///     "knows",
+///     QueryBuilder::select(surrealex::fields!("id")).from("person"),
+/// )
+/// .build();
+/// assert_eq!(
+///     sql,
+///     "RELATE [person:tobie, person:jaime]->knows->(SELECT id FROM person)"
+/// );
+/// ```
+pub trait IntoTarget {
+    /// Convert this value into a SurrealQL target expression.
+    fn into_target(self) -> String;
+}
+
+impl IntoTarget for &str {
+    fn into_target(self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoTarget for String {
+    fn into_target(self) -> String {
+        self
+    }
+}
+
+impl IntoTarget for &String {
+    fn into_target(self) -> String {
+        self.clone()
+    }
+}
+
+impl<S: Into<String>> IntoTarget for Vec<S> {
+    fn into_target(self) -> String {
+        let items = self
+            .into_iter()
+            .map(|s| s.into())
+            .collect::<Vec<String>>()
+            .join(", ");
+        format!("[{items}]")
+    }
+}
+
+/// Trait for values that can be embedded as a complete statement inside
+/// another statement, such as the `THEN` block of a `DEFINE EVENT`.
+///
+/// Implemented for every statement builder (rendering it via `build()`), and for
+/// `&str` / `String`, which are passed through as raw SurrealQL statements.
+///
+/// # Examples
+///
+/// ```
+/// # use surrealex::QueryBuilder;
+/// use surrealex::traits::IntoStatement;
+///
+/// let statement = QueryBuilder::delete("session").r#where("expired = true");
+/// assert_eq!(
+///     statement.into_statement(),
+///     "DELETE FROM session WHERE expired = true"
+/// );
+/// ```
+pub trait IntoStatement {
+    /// Render this value as a SurrealQL statement.
+    fn into_statement(self) -> String;
+
+    /// Returns `true` when this value renders as several `;`-separated
+    /// statements rather than a single one.
+    fn is_multi_statement(&self) -> bool {
+        false
+    }
+}
+
+impl IntoStatement for &str {
+    fn into_statement(self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoStatement for String {
+    fn into_statement(self) -> String {
+        self
+    }
+}
+
 /// Trait for values that can be used as a SurrealQL `TIMEOUT` duration.
 ///
 /// Implemented for:
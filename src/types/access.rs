@@ -0,0 +1,109 @@
+use std::fmt::Display;
+
+use crate::enums::{Base, Condition};
+
+/// The subject a bearer grant is issued for.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GrantSubject {
+    /// `FOR USER @user`
+    User(String),
+    /// `FOR RECORD @record`
+    Record(String),
+}
+
+impl Display for GrantSubject {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GrantSubject::User(user) => write!(f, "FOR USER {user}"),
+            GrantSubject::Record(record) => write!(f, "FOR RECORD {record}"),
+        }
+    }
+}
+
+/// Selects the grants a `SHOW` or `REVOKE` command applies to.
+#[derive(Debug, Clone)]
+pub enum GrantFilter {
+    /// `ALL`
+    All,
+    /// `GRANT @id`
+    Grant(String),
+    /// `WHERE @condition`
+    Where(Condition),
+}
+
+impl Display for GrantFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GrantFilter::All => write!(f, "ALL"),
+            GrantFilter::Grant(id) => write!(f, "GRANT {id}"),
+            GrantFilter::Where(condition) => write!(f, "WHERE {condition}"),
+        }
+    }
+}
+
+/// Selects the grants removed by a `PURGE` command.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PurgeTarget {
+    /// `EXPIRED`
+    Expired,
+    /// `REVOKED`
+    Revoked,
+    /// `EXPIRED, REVOKED`
+    ExpiredAndRevoked,
+}
+
+impl Display for PurgeTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PurgeTarget::Expired => write!(f, "EXPIRED"),
+            PurgeTarget::Revoked => write!(f, "REVOKED"),
+            PurgeTarget::ExpiredAndRevoked => write!(f, "EXPIRED, REVOKED"),
+        }
+    }
+}
+
+/// The sub-command of an ACCESS statement.
+#[derive(Debug, Clone)]
+pub enum AccessCommand {
+    /// `GRANT FOR USER | RECORD ...`
+    Grant(GrantSubject),
+    /// `SHOW ALL | GRANT @id | WHERE @condition`
+    Show(GrantFilter),
+    /// `REVOKE ALL | GRANT @id | WHERE @condition`
+    Revoke(GrantFilter),
+    /// `PURGE EXPIRED | REVOKED [FOR @duration]`
+    Purge {
+        /// The kind of grants to delete.
+        target: PurgeTarget,
+        /// Optional grace period, as a raw SurrealQL duration string.
+        grace: Option<String>,
+    },
+}
+
+impl Display for AccessCommand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AccessCommand::Grant(subject) => write!(f, "GRANT {subject}"),
+            AccessCommand::Show(filter) => write!(f, "SHOW {filter}"),
+            AccessCommand::Revoke(filter) => write!(f, "REVOKE {filter}"),
+            AccessCommand::Purge { target, grace } => {
+                write!(f, "PURGE {target}")?;
+                if let Some(grace) = grace {
+                    write!(f, " FOR {grace}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Holds all the data needed to build an ACCESS statement.
+#[derive(Debug, Clone)]
+pub struct AccessData {
+    /// The access method name.
+    pub name: String,
+    /// Optional level the access method is defined on.
+    pub base: Option<Base>,
+    /// The sub-command.
+    pub command: AccessCommand,
+}
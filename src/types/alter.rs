@@ -0,0 +1,21 @@
+use crate::types::define::{Changefeed, Permissions, table::SchemaMode};
+
+/// Holds all the data needed to build an ALTER TABLE statement.
+///
+/// Only properties that can be changed on an existing table are represented;
+/// unset properties are left untouched by the server.
+#[derive(Default, Debug, Clone)]
+pub struct AlterTableData {
+    /// The table name (e.g., `"person"`).
+    pub name: String,
+    /// When `true`, emits `IF EXISTS` after `ALTER TABLE`.
+    pub if_exists: bool,
+    /// Optional `SCHEMAFULL` / `SCHEMALESS` mode.
+    pub schema: Option<SchemaMode>,
+    /// Optional PERMISSIONS clause.
+    pub permissions: Option<Permissions>,
+    /// Optional `CHANGEFEED` clause.
+    pub changefeed: Option<Changefeed>,
+    /// Optional comment, rendered as a quoted string.
+    pub comment: Option<String>,
+}
@@ -0,0 +1,57 @@
+use crate::{internal_utils::quote_str, traits::IntoStatement};
+
+/// The `BREAK` statement, exiting the enclosing `FOR` loop.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Break;
+
+impl IntoStatement for Break {
+    fn into_statement(self) -> String {
+        "BREAK".to_string()
+    }
+}
+
+/// The `CONTINUE` statement, skipping to the next iteration of the enclosing
+/// `FOR` loop.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Continue;
+
+impl IntoStatement for Continue {
+    fn into_statement(self) -> String {
+        "CONTINUE".to_string()
+    }
+}
+
+/// The `THROW @value` statement, aborting execution with an error.
+///
+/// ```
+/// use surrealex::traits::IntoStatement;
+/// use surrealex::types::control::Throw;
+///
+/// assert_eq!(Throw::new("Not allowed").into_statement(), "THROW \"Not allowed\"");
+/// assert_eq!(Throw::expr("$error").into_statement(), "THROW $error");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Throw {
+    /// The rendered error value.
+    pub value: String,
+}
+
+impl Throw {
+    /// Throws the given message, rendered as a quoted string.
+    pub fn new(message: &str) -> Self {
+        Throw {
+            value: quote_str(message),
+        }
+    }
+
+    /// Throws the value of a raw SurrealQL expression (e.g. a `$param`).
+    pub fn expr(expr: impl Into<String>) -> Self {
+        Throw { value: expr.into() }
+    }
+}
+
+impl IntoStatement for Throw {
+    fn into_statement(self) -> String {
+        format!("THROW {}", self.value)
+    }
+}
@@ -1,16 +1,59 @@
+use std::fmt::Display;
+
 use crate::enums::ReturnClause;
 
-/// Represents the data-setting mode for a CREATE statement.
+/// Represents the data-setting mode shared by CREATE, UPDATE and UPSERT statements.
 ///
-/// SurrealQL supports two mutually exclusive ways to set data on a new record:
+/// SurrealQL supports several mutually exclusive ways to set data on a record:
 /// - `CONTENT @value` — a raw JSON/SurrealQL object
 /// - `SET @field = @value, ...` — individual field assignments
+/// - `UNSET @field, ...` — removal of individual fields
+/// - `MERGE @value` — a partial object merged into the record
+/// - `PATCH @value` — a JSON Patch array applied to the record
+/// - `REPLACE @value` — an object that replaces the record
+///
+/// `CREATE` only accepts `CONTENT` and `SET`.
+///
+/// New data-setting clauses may be added in minor releases, so matches on this
+/// enum need a wildcard arm.
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub enum ContentMode {
     /// `CONTENT @value`
     Content(String),
     /// `SET @field = @value, ...`
     Set(Vec<SetField>),
+    /// `UNSET @field, ...`
+    Unset(Vec<String>),
+    /// `MERGE @value`
+    Merge(String),
+    /// `PATCH @value`
+    Patch(String),
+    /// `REPLACE @value`
+    Replace(String),
+}
+
+impl Display for ContentMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ContentMode::Content(value) => write!(f, "CONTENT {value}"),
+            ContentMode::Set(fields) => {
+                let assignments = fields
+                    .iter()
+                    .map(|f| format!("{} = {}", f.field, f.value))
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                write!(f, "SET {assignments}")
+            }
+            ContentMode::Unset(fields) => {
+                let joined = fields.join(", ");
+                write!(f, "UNSET {joined}")
+            }
+            ContentMode::Merge(value) => write!(f, "MERGE {value}"),
+            ContentMode::Patch(value) => write!(f, "PATCH {value}"),
+            ContentMode::Replace(value) => write!(f, "REPLACE {value}"),
+        }
+    }
 }
 
 /// A single `field = value` pair used in the `SET` clause.
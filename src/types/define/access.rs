@@ -0,0 +1,209 @@
+use std::fmt::Display;
+
+use crate::{enums::Base, internal_utils::quote_str, types::define::DefineMode};
+
+/// Signing algorithms accepted for JWT verification.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JwtAlgorithm {
+    /// `EDDSA`
+    Eddsa,
+    /// `ES256`
+    Es256,
+    /// `ES384`
+    Es384,
+    /// `ES512`
+    Es512,
+    /// `HS256`
+    Hs256,
+    /// `HS384`
+    Hs384,
+    /// `HS512`
+    Hs512,
+    /// `PS256`
+    Ps256,
+    /// `PS384`
+    Ps384,
+    /// `PS512`
+    Ps512,
+    /// `RS256`
+    Rs256,
+    /// `RS384`
+    Rs384,
+    /// `RS512`
+    Rs512,
+}
+
+impl Display for JwtAlgorithm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JwtAlgorithm::Eddsa => write!(f, "EDDSA"),
+            JwtAlgorithm::Es256 => write!(f, "ES256"),
+            JwtAlgorithm::Es384 => write!(f, "ES384"),
+            JwtAlgorithm::Es512 => write!(f, "ES512"),
+            JwtAlgorithm::Hs256 => write!(f, "HS256"),
+            JwtAlgorithm::Hs384 => write!(f, "HS384"),
+            JwtAlgorithm::Hs512 => write!(f, "HS512"),
+            JwtAlgorithm::Ps256 => write!(f, "PS256"),
+            JwtAlgorithm::Ps384 => write!(f, "PS384"),
+            JwtAlgorithm::Ps512 => write!(f, "PS512"),
+            JwtAlgorithm::Rs256 => write!(f, "RS256"),
+            JwtAlgorithm::Rs384 => write!(f, "RS384"),
+            JwtAlgorithm::Rs512 => write!(f, "RS512"),
+        }
+    }
+}
+
+/// How incoming JWTs are verified.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JwtVerify {
+    /// `ALGORITHM @algorithm KEY @key`
+    Key {
+        algorithm: JwtAlgorithm,
+        key: String,
+    },
+    /// `URL @url`, pointing to a JWKS endpoint.
+    Url(String),
+}
+
+impl JwtVerify {
+    /// Verify tokens with the given algorithm and key.
+    pub fn key(algorithm: JwtAlgorithm, key: impl Into<String>) -> Self {
+        JwtVerify::Key {
+            algorithm,
+            key: key.into(),
+        }
+    }
+
+    /// Verify tokens against the keys published at a JWKS URL.
+    pub fn url(url: impl Into<String>) -> Self {
+        JwtVerify::Url(url.into())
+    }
+}
+
+impl Display for JwtVerify {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JwtVerify::Key { algorithm, key } => {
+                write!(f, "ALGORITHM {algorithm} KEY {}", quote_str(key))
+            }
+            JwtVerify::Url(url) => write!(f, "URL {}", quote_str(url)),
+        }
+    }
+}
+
+/// The kind of subject a bearer access grants keys to.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BearerSubject {
+    /// `FOR USER`
+    User,
+    /// `FOR RECORD`
+    Record,
+}
+
+impl Display for BearerSubject {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BearerSubject::User => write!(f, "USER"),
+            BearerSubject::Record => write!(f, "RECORD"),
+        }
+    }
+}
+
+/// The level a SurrealDB 1.x `DEFINE TOKEN` is defined on.
+///
+/// Unlike 2.0 access methods, 1.x tokens can't be defined on the root level.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum TokenBase {
+    /// `NAMESPACE`
+    Namespace,
+    /// `DATABASE`
+    #[default]
+    Database,
+}
+
+impl From<TokenBase> for Base {
+    fn from(base: TokenBase) -> Self {
+        match base {
+            TokenBase::Namespace => Base::Namespace,
+            TokenBase::Database => Base::Database,
+        }
+    }
+}
+
+/// The access method of a DEFINE ACCESS statement.
+#[derive(Debug, Clone)]
+pub enum AccessType {
+    /// `TYPE RECORD [SIGNUP (..)] [SIGNIN (..)] [WITH JWT ..]`
+    Record {
+        /// Optional `SIGNUP` statement.
+        signup: Option<String>,
+        /// Optional `SIGNIN` statement.
+        signin: Option<String>,
+        /// Optional `WITH JWT` verification.
+        jwt: Option<JwtVerify>,
+    },
+    /// `TYPE JWT ..`
+    Jwt(JwtVerify),
+    /// `TYPE BEARER FOR USER | RECORD`
+    Bearer(BearerSubject),
+}
+
+impl AccessType {
+    /// A `TYPE RECORD` access without any clauses.
+    pub fn record() -> Self {
+        AccessType::Record {
+            signup: None,
+            signin: None,
+            jwt: None,
+        }
+    }
+}
+
+/// The `DURATION FOR GRANT .., FOR TOKEN .., FOR SESSION ..` clause.
+#[derive(Default, Debug, Clone)]
+pub struct AccessDurations {
+    /// Optional grant duration, as a raw SurrealQL duration string.
+    pub grant: Option<String>,
+    /// Optional token duration, as a raw SurrealQL duration string.
+    pub token: Option<String>,
+    /// Optional session duration, as a raw SurrealQL duration string.
+    pub session: Option<String>,
+}
+
+impl AccessDurations {
+    pub fn is_empty(&self) -> bool {
+        self.grant.is_none() && self.token.is_none() && self.session.is_none()
+    }
+}
+
+impl Display for AccessDurations {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let durations = [
+            ("GRANT", &self.grant),
+            ("TOKEN", &self.token),
+            ("SESSION", &self.session),
+        ]
+        .into_iter()
+        .filter_map(|(kind, duration)| duration.as_ref().map(|d| format!("FOR {kind} {d}")))
+        .collect::<Vec<String>>()
+        .join(", ");
+        write!(f, "{durations}")
+    }
+}
+
+/// Holds all the data needed to build a DEFINE ACCESS statement.
+#[derive(Default, Debug, Clone)]
+pub struct DefineAccessData {
+    /// The access method name.
+    pub name: String,
+    /// The level the access method is defined on (defaults to `DATABASE`).
+    pub base: Base,
+    /// Optional `OVERWRITE` / `IF NOT EXISTS` modifier.
+    pub mode: Option<DefineMode>,
+    /// Optional access type (`RECORD`, `JWT` or `BEARER`).
+    pub access_type: Option<AccessType>,
+    /// Optional `DURATION` clause.
+    pub durations: AccessDurations,
+    /// Optional comment, rendered as a quoted string.
+    pub comment: Option<String>,
+}
@@ -0,0 +1,147 @@
+use std::fmt::Display;
+
+use crate::{internal_utils::quote_str, types::define::DefineMode};
+
+/// Splits text into tokens.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Tokenizer {
+    /// `blank`: splits on whitespace.
+    Blank,
+    /// `camel`: splits on camel-case boundaries.
+    Camel,
+    /// `class`: splits on changes of character class (digit, letter, punctuation, ...).
+    Class,
+    /// `punct`: splits on punctuation.
+    Punct,
+}
+
+impl Display for Tokenizer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tokenizer::Blank => write!(f, "blank"),
+            Tokenizer::Camel => write!(f, "camel"),
+            Tokenizer::Class => write!(f, "class"),
+            Tokenizer::Punct => write!(f, "punct"),
+        }
+    }
+}
+
+/// Languages supported by the `snowball` stemming filter.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Language {
+    Arabic,
+    Danish,
+    Dutch,
+    English,
+    Finnish,
+    French,
+    German,
+    Greek,
+    Hungarian,
+    Italian,
+    Norwegian,
+    Portuguese,
+    Romanian,
+    Russian,
+    Spanish,
+    Swedish,
+    Tamil,
+    Turkish,
+}
+
+impl Display for Language {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Language::Arabic => write!(f, "arabic"),
+            Language::Danish => write!(f, "danish"),
+            Language::Dutch => write!(f, "dutch"),
+            Language::English => write!(f, "english"),
+            Language::Finnish => write!(f, "finnish"),
+            Language::French => write!(f, "french"),
+            Language::German => write!(f, "german"),
+            Language::Greek => write!(f, "greek"),
+            Language::Hungarian => write!(f, "hungarian"),
+            Language::Italian => write!(f, "italian"),
+            Language::Norwegian => write!(f, "norwegian"),
+            Language::Portuguese => write!(f, "portuguese"),
+            Language::Romanian => write!(f, "romanian"),
+            Language::Russian => write!(f, "russian"),
+            Language::Spanish => write!(f, "spanish"),
+            Language::Swedish => write!(f, "swedish"),
+            Language::Tamil => write!(f, "tamil"),
+            Language::Turkish => write!(f, "turkish"),
+        }
+    }
+}
+
+/// Transforms the tokens produced by the tokenizers.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnalyzerFilter {
+    /// `ascii`: replaces non-ASCII characters with their closest ASCII equivalent.
+    Ascii,
+    /// `lowercase`
+    Lowercase,
+    /// `uppercase`
+    Uppercase,
+    /// `edgengram(@min, @max)`: prefixes of each token.
+    EdgeNgram { min: u32, max: u32 },
+    /// `ngram(@min, @max)`: all substrings of each token.
+    Ngram { min: u32, max: u32 },
+    /// `snowball(@language)`: stems each token.
+    Snowball(Language),
+    /// `mapper(@path)`: replaces tokens using a mapping file.
+    Mapper(String),
+}
+
+impl AnalyzerFilter {
+    /// Creates an `edgengram(min, max)` filter.
+    pub fn edgengram(min: u32, max: u32) -> Self {
+        AnalyzerFilter::EdgeNgram { min, max }
+    }
+
+    /// Creates an `ngram(min, max)` filter.
+    pub fn ngram(min: u32, max: u32) -> Self {
+        AnalyzerFilter::Ngram { min, max }
+    }
+
+    /// Creates a `snowball(language)` filter.
+    pub fn snowball(language: Language) -> Self {
+        AnalyzerFilter::Snowball(language)
+    }
+
+    /// Creates a `mapper("path")` filter.
+    pub fn mapper(path: impl Into<String>) -> Self {
+        AnalyzerFilter::Mapper(path.into())
+    }
+}
+
+impl Display for AnalyzerFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnalyzerFilter::Ascii => write!(f, "ascii"),
+            AnalyzerFilter::Lowercase => write!(f, "lowercase"),
+            AnalyzerFilter::Uppercase => write!(f, "uppercase"),
+            AnalyzerFilter::EdgeNgram { min, max } => write!(f, "edgengram({min},{max})"),
+            AnalyzerFilter::Ngram { min, max } => write!(f, "ngram({min},{max})"),
+            AnalyzerFilter::Snowball(language) => write!(f, "snowball({language})"),
+            AnalyzerFilter::Mapper(path) => write!(f, "mapper({})", quote_str(path)),
+        }
+    }
+}
+
+/// Holds all the data needed to build a DEFINE ANALYZER statement.
+#[derive(Default, Debug, Clone)]
+pub struct DefineAnalyzerData {
+    /// The analyzer name.
+    pub name: String,
+    /// Optional `OVERWRITE` / `IF NOT EXISTS` modifier.
+    pub mode: Option<DefineMode>,
+    /// Optional `FUNCTION fn::...` applied to the text before tokenizing.
+    pub function: Option<String>,
+    /// The `TOKENIZERS` list.
+    pub tokenizers: Vec<Tokenizer>,
+    /// The `FILTERS` list.
+    pub filters: Vec<AnalyzerFilter>,
+    /// Optional comment, rendered as a quoted string.
+    pub comment: Option<String>,
+}
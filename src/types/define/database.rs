@@ -0,0 +1,14 @@
+use crate::types::define::{Changefeed, DefineMode};
+
+/// Holds all the data needed to build a DEFINE DATABASE statement.
+#[derive(Default, Debug, Clone)]
+pub struct DefineDatabaseData {
+    /// The database name.
+    pub name: String,
+    /// Optional `OVERWRITE` / `IF NOT EXISTS` modifier.
+    pub mode: Option<DefineMode>,
+    /// Optional comment, rendered as a quoted string.
+    pub comment: Option<String>,
+    /// Optional `CHANGEFEED` clause.
+    pub changefeed: Option<Changefeed>,
+}
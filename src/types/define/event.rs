@@ -0,0 +1,71 @@
+use std::fmt::Display;
+
+use crate::{enums::Condition, types::define::DefineMode};
+
+/// The kind of record change that triggered an event, as exposed by `$event`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EventType {
+    /// `"CREATE"`
+    Create,
+    /// `"UPDATE"`
+    Update,
+    /// `"DELETE"`
+    Delete,
+}
+
+impl Display for EventType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EventType::Create => write!(f, "CREATE"),
+            EventType::Update => write!(f, "UPDATE"),
+            EventType::Delete => write!(f, "DELETE"),
+        }
+    }
+}
+
+/// Converts an event type into the `$event = "..."` condition.
+///
+/// ```
+/// # use surrealex::enums::Condition;
+/// use surrealex::types::define::event::EventType;
+///
+/// assert_eq!(Condition::from(EventType::Create).to_string(), "$event = \"CREATE\"");
+/// ```
+impl From<EventType> for Condition {
+    fn from(event: EventType) -> Self {
+        Condition::Simple(format!("$event = \"{event}\""))
+    }
+}
+
+/// Returns the `$before.<path>` expression, i.e. the value of `path` before the change.
+pub fn before(path: &str) -> String {
+    format!("$before.{path}")
+}
+
+/// Returns the `$after.<path>` expression, i.e. the value of `path` after the change.
+pub fn after(path: &str) -> String {
+    format!("$after.{path}")
+}
+
+/// Returns a condition that holds when `path` was changed by the event
+/// (`$before.<path> != $after.<path>`).
+pub fn changed(path: &str) -> Condition {
+    Condition::Simple(format!("{} != {}", before(path), after(path)))
+}
+
+/// Holds all the data needed to build a DEFINE EVENT statement.
+#[derive(Default, Debug, Clone)]
+pub struct DefineEventData {
+    /// The event name.
+    pub name: String,
+    /// The table the event is attached to.
+    pub table: String,
+    /// Optional `OVERWRITE` / `IF NOT EXISTS` modifier.
+    pub mode: Option<DefineMode>,
+    /// `WHEN` conditions, joined with `AND`.
+    pub when: Vec<Condition>,
+    /// The statements run by the event, rendered into a `THEN { ... }` block.
+    pub then: Vec<String>,
+    /// Optional comment, rendered as a quoted string.
+    pub comment: Option<String>,
+}
@@ -0,0 +1,64 @@
+use crate::{
+    enums::Condition,
+    types::define::{DefineMode, PermissionKind, Permissions, kind::Kind},
+};
+
+/// Represents the `DEFAULT [ALWAYS] @value` clause of a field definition.
+#[derive(Debug, Clone)]
+pub struct FieldDefault {
+    /// The raw default value expression (e.g., `"time::now()"`, `"'guest'"`).
+    pub value: String,
+    /// When `true`, emits `DEFAULT ALWAYS`.
+    pub always: bool,
+}
+
+/// The operations a field `PERMISSIONS FOR ...` rule applies to.
+///
+/// Fields can't be deleted on their own, so unlike [`PermissionKind`] there is
+/// no `delete` kind.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldPermissionKind {
+    /// `select`
+    Select,
+    /// `create`
+    Create,
+    /// `update`
+    Update,
+}
+
+impl From<FieldPermissionKind> for PermissionKind {
+    fn from(kind: FieldPermissionKind) -> Self {
+        match kind {
+            FieldPermissionKind::Select => PermissionKind::Select,
+            FieldPermissionKind::Create => PermissionKind::Create,
+            FieldPermissionKind::Update => PermissionKind::Update,
+        }
+    }
+}
+
+/// Holds all the data needed to build a DEFINE FIELD statement.
+#[derive(Default, Debug, Clone)]
+pub struct DefineFieldData {
+    /// The field name (e.g., `"email"`, `"address.city"`, `"tags.*"`).
+    pub name: String,
+    /// The table the field belongs to.
+    pub table: String,
+    /// Optional `OVERWRITE` / `IF NOT EXISTS` modifier.
+    pub mode: Option<DefineMode>,
+    /// When `true`, emits `FLEXIBLE`.
+    pub flexible: bool,
+    /// Optional `TYPE` clause.
+    pub kind: Option<Kind>,
+    /// Optional `DEFAULT [ALWAYS]` clause.
+    pub default: Option<FieldDefault>,
+    /// When `true`, emits `READONLY`.
+    pub readonly: bool,
+    /// Optional raw `VALUE` expression.
+    pub value: Option<String>,
+    /// `ASSERT` conditions, joined with `AND`.
+    pub assert_clause: Vec<Condition>,
+    /// Optional PERMISSIONS clause.
+    pub permissions: Option<Permissions>,
+    /// Optional comment, rendered as a quoted string.
+    pub comment: Option<String>,
+}
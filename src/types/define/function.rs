@@ -0,0 +1,119 @@
+use std::fmt::Display;
+
+use crate::{
+    enums::Condition,
+    traits::ToSelectField,
+    types::{
+        define::{DefineMode, Permission, kind::Kind},
+        select::SelectField,
+    },
+};
+
+/// A single typed argument of a custom function (`$name: kind`).
+#[derive(Debug, Clone)]
+pub struct FunctionArg {
+    /// The argument name, without the leading `$`.
+    pub name: String,
+    /// The argument type.
+    pub kind: Kind,
+}
+
+impl Display for FunctionArg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "${}: {}", self.name, self.kind)
+    }
+}
+
+/// Holds all the data needed to build a DEFINE FUNCTION statement.
+#[derive(Default, Debug, Clone)]
+pub struct DefineFunctionData {
+    /// The function name, including the `fn::` prefix (e.g., `"fn::greet"`).
+    pub name: String,
+    /// Optional `OVERWRITE` / `IF NOT EXISTS` modifier.
+    pub mode: Option<DefineMode>,
+    /// The typed arguments.
+    pub args: Vec<FunctionArg>,
+    /// Optional return type, rendered as `-> @kind`.
+    pub returns: Option<Kind>,
+    /// The statements of the function body, rendered into a `{ ... }` block.
+    pub body: Vec<String>,
+    /// Optional comment, rendered as a quoted string.
+    pub comment: Option<String>,
+    /// Optional PERMISSIONS clause (`NONE`, `FULL` or `WHERE @condition`).
+    pub permissions: Option<Permission>,
+}
+
+/// A call to a custom function, rendered as `fn::name(arg1, arg2, ...)`.
+///
+/// Can be used as a select field or as a condition.
+///
+/// # Examples
+///
+/// ```
+/// # use surrealex::QueryBuilder;
+/// use surrealex::types::define::function::FunctionCall;
+///
+/// let sql = QueryBuilder::select(surrealex::fields!(
+///     "id",
+///     (FunctionCall::new("fn::greet").arg("name"), "greeting")
+/// ))
+/// .from("person")
+/// .r#where(FunctionCall::new("fn::is_active").arg("id"))
+/// .build();
+/// assert_eq!(
+///     sql,
+///     "SELECT id, fn::greet(name) AS greeting FROM person WHERE fn::is_active(id)"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct FunctionCall {
+    /// The function name, including the `fn::` prefix.
+    pub name: String,
+    /// The raw argument expressions.
+    pub args: Vec<String>,
+}
+
+impl FunctionCall {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            args: Vec::new(),
+        }
+    }
+
+    /// Appends a raw argument expression (e.g., `"'Tobie'"`, `"$value"`).
+    pub fn arg(mut self, arg: impl Into<String>) -> Self {
+        self.args.push(arg.into());
+        self
+    }
+}
+
+impl Display for FunctionCall {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name, self.args.join(", "))
+    }
+}
+
+impl ToSelectField for FunctionCall {
+    fn to_select_field(self) -> SelectField {
+        SelectField {
+            name: self.to_string(),
+            alias: None,
+        }
+    }
+}
+
+impl ToSelectField for (FunctionCall, &str) {
+    fn to_select_field(self) -> SelectField {
+        SelectField {
+            name: self.0.to_string(),
+            alias: Some(self.1.to_string()),
+        }
+    }
+}
+
+impl From<FunctionCall> for Condition {
+    fn from(call: FunctionCall) -> Self {
+        Condition::Simple(call.to_string())
+    }
+}
@@ -0,0 +1,302 @@
+use std::fmt::Display;
+
+use crate::types::define::DefineMode;
+
+/// Distance functions used by vector indexes (`DIST @distance`).
+#[derive(Debug, Clone, PartialEq)]
+pub enum Distance {
+    /// `CHEBYSHEV`
+    Chebyshev,
+    /// `COSINE`
+    Cosine,
+    /// `EUCLIDEAN`
+    Euclidean,
+    /// `HAMMING`
+    Hamming,
+    /// `JACCARD`
+    Jaccard,
+    /// `MANHATTAN`
+    Manhattan,
+    /// `MINKOWSKI @order`
+    Minkowski(u32),
+    /// `PEARSON`
+    Pearson,
+}
+
+impl Display for Distance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Distance::Chebyshev => write!(f, "CHEBYSHEV"),
+            Distance::Cosine => write!(f, "COSINE"),
+            Distance::Euclidean => write!(f, "EUCLIDEAN"),
+            Distance::Hamming => write!(f, "HAMMING"),
+            Distance::Jaccard => write!(f, "JACCARD"),
+            Distance::Manhattan => write!(f, "MANHATTAN"),
+            Distance::Minkowski(order) => write!(f, "MINKOWSKI {order}"),
+            Distance::Pearson => write!(f, "PEARSON"),
+        }
+    }
+}
+
+/// Element types stored by vector indexes (`TYPE @type`).
+#[derive(Debug, Clone, PartialEq)]
+pub enum VectorType {
+    /// `F64`
+    F64,
+    /// `F32`
+    F32,
+    /// `I64`
+    I64,
+    /// `I32`
+    I32,
+    /// `I16`
+    I16,
+}
+
+impl Display for VectorType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VectorType::F64 => write!(f, "F64"),
+            VectorType::F32 => write!(f, "F32"),
+            VectorType::I64 => write!(f, "I64"),
+            VectorType::I32 => write!(f, "I32"),
+            VectorType::I16 => write!(f, "I16"),
+        }
+    }
+}
+
+/// Ranking options for a full-text `SEARCH` index.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Bm25 {
+    /// `BM25` with the server defaults.
+    Default,
+    /// `BM25(@k1, @b)`
+    Params { k1: f64, b: f64 },
+}
+
+impl Display for Bm25 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Bm25::Default => write!(f, "BM25"),
+            Bm25::Params { k1, b } => write!(f, "BM25({k1},{b})"),
+        }
+    }
+}
+
+/// Parameters for a full-text `SEARCH ANALYZER` index.
+#[derive(Debug, Clone)]
+pub struct SearchParams {
+    /// The analyzer used to tokenize the indexed fields.
+    pub analyzer: String,
+    /// Optional `BM25` ranking clause.
+    pub bm25: Option<Bm25>,
+    /// When `true`, emits `HIGHLIGHTS`.
+    pub highlights: bool,
+}
+
+impl SearchParams {
+    pub fn new(analyzer: impl Into<String>) -> Self {
+        Self {
+            analyzer: analyzer.into(),
+            bm25: None,
+            highlights: false,
+        }
+    }
+
+    /// Adds `BM25` ranking with the server defaults.
+    pub fn bm25(mut self) -> Self {
+        self.bm25 = Some(Bm25::Default);
+        self
+    }
+
+    /// Adds `BM25(@k1, @b)` ranking.
+    pub fn bm25_params(mut self, k1: f64, b: f64) -> Self {
+        self.bm25 = Some(Bm25::Params { k1, b });
+        self
+    }
+
+    /// Adds `HIGHLIGHTS`, enabling `search::highlight()` on the index.
+    pub fn highlights(mut self) -> Self {
+        self.highlights = true;
+        self
+    }
+}
+
+impl Display for SearchParams {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SEARCH ANALYZER {}", self.analyzer)?;
+        if let Some(bm25) = &self.bm25 {
+            write!(f, " {bm25}")?;
+        }
+        if self.highlights {
+            write!(f, " HIGHLIGHTS")?;
+        }
+        Ok(())
+    }
+}
+
+/// Parameters for an `MTREE` vector index.
+#[derive(Debug, Clone)]
+pub struct MtreeParams {
+    /// The vector dimension.
+    pub dimension: u32,
+    /// Optional vector element type.
+    pub vector_type: Option<VectorType>,
+    /// Optional distance function.
+    pub dist: Option<Distance>,
+    /// Optional node capacity.
+    pub capacity: Option<u32>,
+}
+
+impl MtreeParams {
+    pub fn new(dimension: u32) -> Self {
+        Self {
+            dimension,
+            vector_type: None,
+            dist: None,
+            capacity: None,
+        }
+    }
+
+    pub fn vector_type(mut self, vector_type: VectorType) -> Self {
+        self.vector_type = Some(vector_type);
+        self
+    }
+
+    pub fn dist(mut self, dist: Distance) -> Self {
+        self.dist = Some(dist);
+        self
+    }
+
+    pub fn capacity(mut self, capacity: u32) -> Self {
+        self.capacity = Some(capacity);
+        self
+    }
+}
+
+impl Display for MtreeParams {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MTREE DIMENSION {}", self.dimension)?;
+        if let Some(vector_type) = &self.vector_type {
+            write!(f, " TYPE {vector_type}")?;
+        }
+        if let Some(dist) = &self.dist {
+            write!(f, " DIST {dist}")?;
+        }
+        if let Some(capacity) = self.capacity {
+            write!(f, " CAPACITY {capacity}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Parameters for an `HNSW` vector index.
+#[derive(Debug, Clone)]
+pub struct HnswParams {
+    /// The vector dimension.
+    pub dimension: u32,
+    /// Optional vector element type.
+    pub vector_type: Option<VectorType>,
+    /// Optional distance function.
+    pub dist: Option<Distance>,
+    /// Optional size of the dynamic candidate list during construction (`EFC`).
+    pub efc: Option<u32>,
+    /// Optional maximum number of connections per node (`M`).
+    pub m: Option<u32>,
+}
+
+impl HnswParams {
+    pub fn new(dimension: u32) -> Self {
+        Self {
+            dimension,
+            vector_type: None,
+            dist: None,
+            efc: None,
+            m: None,
+        }
+    }
+
+    pub fn vector_type(mut self, vector_type: VectorType) -> Self {
+        self.vector_type = Some(vector_type);
+        self
+    }
+
+    pub fn dist(mut self, dist: Distance) -> Self {
+        self.dist = Some(dist);
+        self
+    }
+
+    pub fn efc(mut self, efc: u32) -> Self {
+        self.efc = Some(efc);
+        self
+    }
+
+    pub fn m(mut self, m: u32) -> Self {
+        self.m = Some(m);
+        self
+    }
+}
+
+impl Display for HnswParams {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "HNSW DIMENSION {}", self.dimension)?;
+        if let Some(vector_type) = &self.vector_type {
+            write!(f, " TYPE {vector_type}")?;
+        }
+        if let Some(dist) = &self.dist {
+            write!(f, " DIST {dist}")?;
+        }
+        if let Some(efc) = self.efc {
+            write!(f, " EFC {efc}")?;
+        }
+        if let Some(m) = self.m {
+            write!(f, " M {m}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The kind of index being defined.
+#[derive(Debug, Clone)]
+pub enum IndexKind {
+    /// `UNIQUE`
+    Unique,
+    /// `SEARCH ANALYZER ...`
+    Search(SearchParams),
+    /// `MTREE DIMENSION ...`
+    Mtree(MtreeParams),
+    /// `HNSW DIMENSION ...`
+    Hnsw(HnswParams),
+}
+
+impl Display for IndexKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IndexKind::Unique => write!(f, "UNIQUE"),
+            IndexKind::Search(params) => write!(f, "{params}"),
+            IndexKind::Mtree(params) => write!(f, "{params}"),
+            IndexKind::Hnsw(params) => write!(f, "{params}"),
+        }
+    }
+}
+
+/// Holds all the data needed to build a DEFINE INDEX statement.
+#[derive(Default, Debug, Clone)]
+pub struct DefineIndexData {
+    /// The index name.
+    pub name: String,
+    /// The table the index belongs to.
+    pub table: String,
+    /// Optional `OVERWRITE` / `IF NOT EXISTS` modifier.
+    pub mode: Option<DefineMode>,
+    /// The indexed fields.
+    pub fields: Vec<String>,
+    /// When `true`, emits the `COLUMNS` keyword instead of `FIELDS`.
+    pub columns: bool,
+    /// Optional index kind (`UNIQUE`, `SEARCH`, `MTREE` or `HNSW`).
+    pub kind: Option<IndexKind>,
+    /// Optional comment, rendered as a quoted string.
+    pub comment: Option<String>,
+    /// When `true`, emits `CONCURRENTLY`.
+    pub concurrently: bool,
+}
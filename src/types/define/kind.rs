@@ -0,0 +1,223 @@
+use std::fmt::Display;
+
+use crate::internal_utils::quote_str;
+
+/// Geometry sub-types accepted by `geometry<...>`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GeometryKind {
+    /// `feature` — any geometry type.
+    Feature,
+    /// `point`
+    Point,
+    /// `line`
+    Line,
+    /// `polygon`
+    Polygon,
+    /// `multipoint`
+    MultiPoint,
+    /// `multiline`
+    MultiLine,
+    /// `multipolygon`
+    MultiPolygon,
+    /// `collection`
+    Collection,
+}
+
+impl Display for GeometryKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GeometryKind::Feature => write!(f, "feature"),
+            GeometryKind::Point => write!(f, "point"),
+            GeometryKind::Line => write!(f, "line"),
+            GeometryKind::Polygon => write!(f, "polygon"),
+            GeometryKind::MultiPoint => write!(f, "multipoint"),
+            GeometryKind::MultiLine => write!(f, "multiline"),
+            GeometryKind::MultiPolygon => write!(f, "multipolygon"),
+            GeometryKind::Collection => write!(f, "collection"),
+        }
+    }
+}
+
+/// A SurrealQL data type, as used in `TYPE` clauses and typed arguments.
+///
+/// Composite kinds are built with the helper constructors:
+///
+/// ```
+/// # use surrealex::types::define::kind::{GeometryKind, Kind};
+/// assert_eq!(Kind::option(Kind::String).to_string(), "option<string>");
+/// assert_eq!(Kind::array_max(Kind::Int, 5).to_string(), "array<int, 5>");
+/// assert_eq!(Kind::record(vec!["user", "admin"]).to_string(), "record<user | admin>");
+/// assert_eq!(
+///     Kind::geometry(vec![GeometryKind::Point]).to_string(),
+///     "geometry<point>"
+/// );
+/// assert_eq!(
+///     Kind::either(vec![Kind::string_literal("a"), Kind::string_literal("b")]).to_string(),
+///     "\"a\" | \"b\""
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum Kind {
+    /// `any`
+    Any,
+    /// `null`
+    Null,
+    /// `bool`
+    Bool,
+    /// `bytes`
+    Bytes,
+    /// `datetime`
+    Datetime,
+    /// `decimal`
+    Decimal,
+    /// `duration`
+    Duration,
+    /// `float`
+    Float,
+    /// `int`
+    Int,
+    /// `number`
+    Number,
+    /// `object`
+    Object,
+    /// `string`
+    String,
+    /// `uuid`
+    Uuid,
+    /// `range`
+    Range,
+    /// `function`
+    Function,
+    /// `geometry` or `geometry<@kinds>`
+    Geometry(Vec<GeometryKind>),
+    /// `option<@kind>`
+    Option(Box<Kind>),
+    /// `array`, `array<@kind>` or `array<@kind, @max>`
+    Array(Box<Kind>, Option<u64>),
+    /// `set`, `set<@kind>` or `set<@kind, @max>`
+    Set(Box<Kind>, Option<u64>),
+    /// `record` or `record<@table | ...>`
+    Record(Vec<String>),
+    /// A union of kinds, joined with `|`.
+    Either(Vec<Kind>),
+    /// A raw literal value (e.g., `"a"`, `1`, `{ status: "ok" }`).
+    Literal(String),
+}
+
+impl Kind {
+    /// `option<@kind>`
+    pub fn option(kind: Kind) -> Self {
+        Kind::Option(Box::new(kind))
+    }
+
+    /// `array<@kind>`
+    pub fn array(kind: Kind) -> Self {
+        Kind::Array(Box::new(kind), None)
+    }
+
+    /// `array<@kind, @max>`
+    pub fn array_max(kind: Kind, max: u64) -> Self {
+        Kind::Array(Box::new(kind), Some(max))
+    }
+
+    /// `set<@kind>`
+    pub fn set(kind: Kind) -> Self {
+        Kind::Set(Box::new(kind), None)
+    }
+
+    /// `set<@kind, @max>`
+    pub fn set_max(kind: Kind, max: u64) -> Self {
+        Kind::Set(Box::new(kind), Some(max))
+    }
+
+    /// `record<@table | ...>`, or `record` when `tables` is empty.
+    pub fn record<S: Into<String>>(tables: Vec<S>) -> Self {
+        Kind::Record(tables.into_iter().map(|s| s.into()).collect())
+    }
+
+    /// `geometry<@kind | ...>`, or `geometry` when `kinds` is empty.
+    pub fn geometry(kinds: Vec<GeometryKind>) -> Self {
+        Kind::Geometry(kinds)
+    }
+
+    /// A union of kinds (e.g., `string | int`).
+    pub fn either(kinds: Vec<Kind>) -> Self {
+        Kind::Either(kinds)
+    }
+
+    /// A raw literal kind, passed through as-is (e.g., `"1"`, `"{ a: int }"`).
+    pub fn literal(value: impl Into<String>) -> Self {
+        Kind::Literal(value.into())
+    }
+
+    /// A string literal kind, rendered as a quoted string (e.g., `"admin"`).
+    pub fn string_literal(value: &str) -> Self {
+        Kind::Literal(quote_str(value))
+    }
+}
+
+fn write_collection(
+    f: &mut std::fmt::Formatter<'_>,
+    name: &str,
+    kind: &Kind,
+    max: Option<u64>,
+) -> std::fmt::Result {
+    match (kind, max) {
+        (Kind::Any, None) => write!(f, "{name}"),
+        (kind, None) => write!(f, "{name}<{kind}>"),
+        (kind, Some(max)) => write!(f, "{name}<{kind}, {max}>"),
+    }
+}
+
+impl Display for Kind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Kind::Any => write!(f, "any"),
+            Kind::Null => write!(f, "null"),
+            Kind::Bool => write!(f, "bool"),
+            Kind::Bytes => write!(f, "bytes"),
+            Kind::Datetime => write!(f, "datetime"),
+            Kind::Decimal => write!(f, "decimal"),
+            Kind::Duration => write!(f, "duration"),
+            Kind::Float => write!(f, "float"),
+            Kind::Int => write!(f, "int"),
+            Kind::Number => write!(f, "number"),
+            Kind::Object => write!(f, "object"),
+            Kind::String => write!(f, "string"),
+            Kind::Uuid => write!(f, "uuid"),
+            Kind::Range => write!(f, "range"),
+            Kind::Function => write!(f, "function"),
+            Kind::Geometry(kinds) => {
+                if kinds.is_empty() {
+                    write!(f, "geometry")
+                } else {
+                    let joined = kinds
+                        .iter()
+                        .map(|k| k.to_string())
+                        .collect::<Vec<String>>()
+                        .join(" | ");
+                    write!(f, "geometry<{joined}>")
+                }
+            }
+            Kind::Option(kind) => write!(f, "option<{kind}>"),
+            Kind::Array(kind, max) => write_collection(f, "array", kind, *max),
+            Kind::Set(kind, max) => write_collection(f, "set", kind, *max),
+            Kind::Record(tables) => {
+                if tables.is_empty() {
+                    write!(f, "record")
+                } else {
+                    write!(f, "record<{}>", tables.join(" | "))
+                }
+            }
+            Kind::Either(kinds) => {
+                let joined = kinds
+                    .iter()
+                    .map(|k| k.to_string())
+                    .collect::<Vec<String>>()
+                    .join(" | ");
+                write!(f, "{joined}")
+            }
+            Kind::Literal(value) => write!(f, "{value}"),
+        }
+    }
+}
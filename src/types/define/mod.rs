@@ -0,0 +1,172 @@
+use std::fmt::Display;
+
+use crate::enums::Condition;
+
+pub mod access;
+pub mod analyzer;
+pub mod database;
+pub mod event;
+pub mod field;
+pub mod function;
+pub mod index;
+pub mod kind;
+pub mod namespace;
+pub mod param;
+pub mod table;
+pub mod user;
+
+/// Controls what happens when a DEFINE statement targets an existing resource.
+///
+/// SurrealQL supports: `OVERWRITE` or `IF NOT EXISTS`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DefineMode {
+    /// `OVERWRITE`
+    Overwrite,
+    /// `IF NOT EXISTS`
+    IfNotExists,
+}
+
+impl Display for DefineMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DefineMode::Overwrite => write!(f, "OVERWRITE"),
+            DefineMode::IfNotExists => write!(f, "IF NOT EXISTS"),
+        }
+    }
+}
+
+/// The operations a `PERMISSIONS FOR ...` rule applies to.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PermissionKind {
+    /// `select`
+    Select,
+    /// `create`
+    Create,
+    /// `update`
+    Update,
+    /// `delete`
+    Delete,
+}
+
+impl Display for PermissionKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PermissionKind::Select => write!(f, "select"),
+            PermissionKind::Create => write!(f, "create"),
+            PermissionKind::Update => write!(f, "update"),
+            PermissionKind::Delete => write!(f, "delete"),
+        }
+    }
+}
+
+/// A single permission value: `NONE`, `FULL` or `WHERE @condition`.
+#[derive(Debug, Clone)]
+pub enum Permission {
+    /// `NONE`
+    None,
+    /// `FULL`
+    Full,
+    /// `WHERE @condition`
+    Where(Condition),
+}
+
+impl Display for Permission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Permission::None => write!(f, "NONE"),
+            Permission::Full => write!(f, "FULL"),
+            Permission::Where(condition) => write!(f, "WHERE {condition}"),
+        }
+    }
+}
+
+impl From<Condition> for Permission {
+    fn from(condition: Condition) -> Self {
+        Permission::Where(condition)
+    }
+}
+
+impl From<&str> for Permission {
+    fn from(s: &str) -> Self {
+        Permission::Where(Condition::from(s))
+    }
+}
+
+impl From<String> for Permission {
+    fn from(s: String) -> Self {
+        Permission::Where(Condition::from(s))
+    }
+}
+
+/// A single `FOR @kinds @permission` rule.
+#[derive(Debug, Clone)]
+pub struct PermissionRule {
+    /// The operations the rule applies to (e.g., `select, update`).
+    pub kinds: Vec<PermissionKind>,
+    /// The permission granted for those operations.
+    pub permission: Permission,
+}
+
+impl Display for PermissionRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kinds = self
+            .kinds
+            .iter()
+            .map(|kind| kind.to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+        write!(f, "FOR {kinds} {}", self.permission)
+    }
+}
+
+/// Represents the PERMISSIONS clause of a table or field definition.
+///
+/// SurrealQL supports:
+/// - `PERMISSIONS NONE`
+/// - `PERMISSIONS FULL`
+/// - `PERMISSIONS FOR select @permission FOR create, update @permission ...`
+#[derive(Debug, Clone)]
+pub enum Permissions {
+    /// `PERMISSIONS NONE`
+    None,
+    /// `PERMISSIONS FULL`
+    Full,
+    /// `PERMISSIONS FOR ... FOR ...`
+    For(Vec<PermissionRule>),
+}
+
+impl Display for Permissions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Permissions::None => write!(f, "NONE"),
+            Permissions::Full => write!(f, "FULL"),
+            Permissions::For(rules) => {
+                let joined = rules
+                    .iter()
+                    .map(|rule| rule.to_string())
+                    .collect::<Vec<String>>()
+                    .join(" ");
+                write!(f, "{joined}")
+            }
+        }
+    }
+}
+
+/// Represents the `CHANGEFEED @duration [INCLUDE ORIGINAL]` clause.
+#[derive(Debug, Clone)]
+pub struct Changefeed {
+    /// How long changes are retained, as a raw SurrealQL duration string (e.g., `"3d"`).
+    pub duration: String,
+    /// When `true`, emits `INCLUDE ORIGINAL` after the duration.
+    pub include_original: bool,
+}
+
+impl Display for Changefeed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.duration)?;
+        if self.include_original {
+            write!(f, " INCLUDE ORIGINAL")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,12 @@
+use crate::types::define::DefineMode;
+
+/// Holds all the data needed to build a DEFINE NAMESPACE statement.
+#[derive(Default, Debug, Clone)]
+pub struct DefineNamespaceData {
+    /// The namespace name.
+    pub name: String,
+    /// Optional `OVERWRITE` / `IF NOT EXISTS` modifier.
+    pub mode: Option<DefineMode>,
+    /// Optional comment, rendered as a quoted string.
+    pub comment: Option<String>,
+}
@@ -0,0 +1,16 @@
+use crate::types::define::{DefineMode, Permission};
+
+/// Holds all the data needed to build a DEFINE PARAM statement.
+#[derive(Default, Debug, Clone)]
+pub struct DefineParamData {
+    /// The parameter name, without the leading `$`.
+    pub name: String,
+    /// Optional `OVERWRITE` / `IF NOT EXISTS` modifier.
+    pub mode: Option<DefineMode>,
+    /// The `VALUE` expression.
+    pub value: String,
+    /// Optional comment, rendered as a quoted string.
+    pub comment: Option<String>,
+    /// Optional `PERMISSIONS NONE | FULL | WHERE ...` clause.
+    pub permissions: Option<Permission>,
+}
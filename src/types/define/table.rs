@@ -0,0 +1,89 @@
+use std::fmt::Display;
+
+use crate::types::define::{Changefeed, DefineMode, Permissions};
+
+/// Schema enforcement mode of a table.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SchemaMode {
+    /// `SCHEMAFULL`
+    Schemafull,
+    /// `SCHEMALESS`
+    Schemaless,
+}
+
+impl Display for SchemaMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SchemaMode::Schemafull => write!(f, "SCHEMAFULL"),
+            SchemaMode::Schemaless => write!(f, "SCHEMALESS"),
+        }
+    }
+}
+
+/// Represents the `TYPE` clause of a table definition.
+///
+/// SurrealQL supports:
+/// - `TYPE ANY`
+/// - `TYPE NORMAL`
+/// - `TYPE RELATION [IN @from] [OUT @to] [ENFORCED]`
+#[derive(Debug, Clone)]
+pub enum TableType {
+    /// `TYPE ANY`
+    Any,
+    /// `TYPE NORMAL`
+    Normal,
+    /// `TYPE RELATION IN @from OUT @to [ENFORCED]`
+    Relation {
+        /// Tables allowed on the `in` side (joined with `|`).
+        from: Vec<String>,
+        /// Tables allowed on the `out` side (joined with `|`).
+        to: Vec<String>,
+        /// When `true`, emits `ENFORCED`.
+        enforced: bool,
+    },
+}
+
+impl Display for TableType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TableType::Any => write!(f, "ANY"),
+            TableType::Normal => write!(f, "NORMAL"),
+            TableType::Relation { from, to, enforced } => {
+                write!(f, "RELATION")?;
+                if !from.is_empty() {
+                    write!(f, " IN {}", from.join(" | "))?;
+                }
+                if !to.is_empty() {
+                    write!(f, " OUT {}", to.join(" | "))?;
+                }
+                if *enforced {
+                    write!(f, " ENFORCED")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Holds all the data needed to build a DEFINE TABLE statement.
+#[derive(Default, Debug, Clone)]
+pub struct DefineTableData {
+    /// The table name (e.g., `"person"`).
+    pub name: String,
+    /// Optional `OVERWRITE` / `IF NOT EXISTS` modifier.
+    pub mode: Option<DefineMode>,
+    /// When `true`, emits `DROP`.
+    pub drop: bool,
+    /// Optional `SCHEMAFULL` / `SCHEMALESS` mode.
+    pub schema: Option<SchemaMode>,
+    /// Optional `TYPE ANY | NORMAL | RELATION ...` clause.
+    pub table_type: Option<TableType>,
+    /// Optional view definition, rendered as `AS @select`.
+    pub view: Option<String>,
+    /// Optional `CHANGEFEED` clause.
+    pub changefeed: Option<Changefeed>,
+    /// Optional PERMISSIONS clause.
+    pub permissions: Option<Permissions>,
+    /// Optional comment, rendered as a quoted string.
+    pub comment: Option<String>,
+}
@@ -0,0 +1,55 @@
+use std::fmt::Display;
+
+use crate::{
+    enums::Base,
+    types::define::{DefineMode, access::AccessDurations},
+};
+
+/// Built-in roles that can be assigned to a system user.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Role {
+    /// `OWNER`
+    Owner,
+    /// `EDITOR`
+    Editor,
+    /// `VIEWER`
+    Viewer,
+}
+
+impl Display for Role {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Role::Owner => write!(f, "OWNER"),
+            Role::Editor => write!(f, "EDITOR"),
+            Role::Viewer => write!(f, "VIEWER"),
+        }
+    }
+}
+
+/// The credential of a system user.
+#[derive(Debug, Clone)]
+pub enum UserCredential {
+    /// `PASSWORD @password`
+    Password(String),
+    /// `PASSHASH @hash`
+    Passhash(String),
+}
+
+/// Holds all the data needed to build a DEFINE USER statement.
+#[derive(Default, Debug, Clone)]
+pub struct DefineUserData {
+    /// The user name.
+    pub name: String,
+    /// The level the user is defined on (defaults to `DATABASE`).
+    pub base: Base,
+    /// Optional `OVERWRITE` / `IF NOT EXISTS` modifier.
+    pub mode: Option<DefineMode>,
+    /// Optional `PASSWORD` / `PASSHASH` credential.
+    pub credential: Option<UserCredential>,
+    /// The assigned roles.
+    pub roles: Vec<Role>,
+    /// Optional `DURATION` clause.
+    pub durations: AccessDurations,
+    /// Optional comment, rendered as a quoted string.
+    pub comment: Option<String>,
+}
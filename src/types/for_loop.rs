@@ -0,0 +1,10 @@
+/// Holds all the data needed to build a FOR loop.
+#[derive(Default, Debug, Clone)]
+pub struct ForLoopData {
+    /// The loop variable name, without the leading `$`.
+    pub item: String,
+    /// The rendered iterable expression.
+    pub iterable: String,
+    /// The rendered body statements, in order.
+    pub body: Vec<String>,
+}
@@ -0,0 +1,39 @@
+use crate::{enums::Condition, traits::IntoStatement};
+
+/// The rendered body of an `IF` / `ELSE IF` / `ELSE` branch.
+#[derive(Debug, Clone)]
+pub struct IfBody {
+    /// The rendered statement, or `;`-separated statements.
+    pub statement: String,
+    /// When `true`, `statement` holds several statements.
+    pub multi_statement: bool,
+}
+
+impl IfBody {
+    /// Renders `body`, remembering whether it holds several statements.
+    pub fn new(body: impl IntoStatement) -> Self {
+        let multi_statement = body.is_multi_statement();
+        IfBody {
+            statement: body.into_statement(),
+            multi_statement,
+        }
+    }
+}
+
+/// A single `IF` / `ELSE IF` branch.
+#[derive(Debug, Clone)]
+pub struct IfBranch {
+    /// The guard condition.
+    pub condition: Condition,
+    /// The branch body.
+    pub body: IfBody,
+}
+
+/// Holds all the data needed to build an IF / ELSE statement.
+#[derive(Default, Debug, Clone)]
+pub struct IfData {
+    /// The `IF` branch followed by any `ELSE IF` branches, in order.
+    pub branches: Vec<IfBranch>,
+    /// Optional body of the final `ELSE` branch.
+    pub else_body: Option<IfBody>,
+}
@@ -0,0 +1,47 @@
+use std::fmt::Display;
+
+use crate::enums::Base;
+
+/// The scope an INFO statement inspects.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub enum InfoScope {
+    /// `ROOT`
+    Root,
+    /// `NS`
+    Namespace,
+    /// `DB`
+    #[default]
+    Database,
+    /// `TABLE @name`
+    Table(String),
+    /// `USER @name [ON @base]`
+    User { name: String, base: Option<Base> },
+}
+
+impl Display for InfoScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InfoScope::Root => write!(f, "ROOT"),
+            InfoScope::Namespace => write!(f, "NS"),
+            InfoScope::Database => write!(f, "DB"),
+            InfoScope::Table(name) => write!(f, "TABLE {name}"),
+            InfoScope::User { name, base } => {
+                write!(f, "USER {name}")?;
+                if let Some(base) = base {
+                    write!(f, " ON {base}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Holds all the data needed to build an INFO statement.
+#[derive(Default, Debug, Clone)]
+pub struct InfoData {
+    /// The inspected scope.
+    pub scope: InfoScope,
+    /// When `true`, emits `STRUCTURE`, returning the definitions as objects
+    /// instead of SurrealQL strings.
+    pub structure: bool,
+}
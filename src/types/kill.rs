@@ -0,0 +1,6 @@
+/// Holds all the data needed to build a KILL statement.
+#[derive(Default, Debug, Clone)]
+pub struct KillData {
+    /// The live query to kill: a quoted UUID string or a `$param`.
+    pub target: String,
+}
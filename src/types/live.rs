@@ -0,0 +1,14 @@
+use crate::{enums::Condition, types::select::SelectField};
+
+/// Holds all the data needed to build a LIVE SELECT statement.
+#[derive(Default, Debug, Clone)]
+pub struct LiveData {
+    /// The projected fields. Ignored when `diff` is `true`.
+    pub fields: Vec<SelectField>,
+    /// When `true`, emits `LIVE SELECT DIFF`, streaming JSON Patch changes.
+    pub diff: bool,
+    /// The table to watch.
+    pub table: String,
+    pub where_clause: Vec<Condition>,
+    pub fetch_fields: Vec<String>,
+}
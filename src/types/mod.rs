@@ -1,4 +1,21 @@
+pub mod access;
+pub mod alter;
+pub mod control;
 pub mod create;
+pub mod define;
 pub mod delete;
+pub mod for_loop;
+pub mod if_else;
+pub mod info;
 pub mod insert;
+pub mod kill;
+pub mod live;
+pub mod relate;
+pub mod remove;
+pub mod script;
 pub mod select;
+pub mod show;
+pub mod transaction;
+pub mod update;
+pub mod upsert;
+pub mod utility;
@@ -0,0 +1,26 @@
+use crate::enums::ReturnClause;
+use crate::types::create::ContentMode;
+
+/// Holds all the data needed to build a RELATE statement.
+#[derive(Default, Debug, Clone)]
+pub struct RelateData {
+    /// The source of the relation (`@from`), e.g. `"person:tobie"` or a subquery.
+    pub from: String,
+    /// The edge table the relation is stored in (e.g., `"wrote"`).
+    pub edge: String,
+    /// The destination of the relation (`@with`), e.g. `"article:surreal"` or a subquery.
+    pub to: String,
+    /// When `true`, emits `RELATE ONLY` instead of `RELATE`.
+    ///
+    /// **Note:** SurrealDB expects a single-result `RETURN` when using `ONLY`.
+    /// The builder does not enforce this — the server will validate it at runtime.
+    pub only: bool,
+    /// Optional data-setting mode (`CONTENT` or `SET`).
+    pub content: Option<ContentMode>,
+    /// Optional RETURN clause (`RETURN NONE | BEFORE | AFTER | DIFF | <params> | VALUE <param>`).
+    pub return_clause: Option<ReturnClause>,
+    /// Optional TIMEOUT duration as a raw SurrealQL duration string (e.g., `"2s"`, `"500ms"`).
+    pub timeout: Option<String>,
+    /// When `true`, emits the `PARALLEL` keyword.
+    pub parallel: bool,
+}
@@ -0,0 +1,66 @@
+use std::fmt::Display;
+
+use crate::enums::Base;
+
+/// The kind of resource a REMOVE statement targets.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub enum RemoveResource {
+    /// `REMOVE NAMESPACE @name`
+    Namespace,
+    /// `REMOVE DATABASE @name`
+    Database,
+    /// `REMOVE TABLE @name`
+    #[default]
+    Table,
+    /// `REMOVE FIELD @name ON TABLE @table`
+    Field { table: String },
+    /// `REMOVE INDEX @name ON TABLE @table`
+    Index { table: String },
+    /// `REMOVE EVENT @name ON TABLE @table`
+    Event { table: String },
+    /// `REMOVE FUNCTION fn::@name`
+    Function,
+    /// `REMOVE PARAM $@name`
+    Param,
+    /// `REMOVE ANALYZER @name`
+    Analyzer,
+    /// `REMOVE ACCESS @name ON @base`
+    Access { base: Base },
+    /// `REMOVE USER @name ON @base`
+    User { base: Base },
+    /// `REMOVE SCOPE @name` (SurrealDB 1.x)
+    Scope,
+    /// `REMOVE TOKEN @name ON @base` (SurrealDB 1.x)
+    Token { base: Base },
+}
+
+impl Display for RemoveResource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RemoveResource::Namespace => write!(f, "NAMESPACE"),
+            RemoveResource::Database => write!(f, "DATABASE"),
+            RemoveResource::Table => write!(f, "TABLE"),
+            RemoveResource::Field { .. } => write!(f, "FIELD"),
+            RemoveResource::Index { .. } => write!(f, "INDEX"),
+            RemoveResource::Event { .. } => write!(f, "EVENT"),
+            RemoveResource::Function => write!(f, "FUNCTION"),
+            RemoveResource::Param => write!(f, "PARAM"),
+            RemoveResource::Analyzer => write!(f, "ANALYZER"),
+            RemoveResource::Access { .. } => write!(f, "ACCESS"),
+            RemoveResource::User { .. } => write!(f, "USER"),
+            RemoveResource::Scope => write!(f, "SCOPE"),
+            RemoveResource::Token { .. } => write!(f, "TOKEN"),
+        }
+    }
+}
+
+/// Holds all the data needed to build a REMOVE statement.
+#[derive(Default, Debug, Clone)]
+pub struct RemoveData {
+    /// The kind of resource being removed.
+    pub resource: RemoveResource,
+    /// The resource name.
+    pub name: String,
+    /// When `true`, emits `IF EXISTS` after the resource kind.
+    pub if_exists: bool,
+}
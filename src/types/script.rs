@@ -0,0 +1,76 @@
+use std::fmt::Display;
+
+use crate::{enums::Condition, traits::IntoTarget};
+
+/// A handle to a `$param` bound by a [`Script`](crate::builders::script::Script)
+/// `LET` statement.
+///
+/// Handles render as `$name` and can be used wherever a target, value
+/// expression or condition is expected.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Param {
+    name: String,
+}
+
+impl Param {
+    /// Creates a handle for an existing parameter. A leading `$` is optional.
+    pub fn new(name: &str) -> Self {
+        Param {
+            name: name.trim_start_matches('$').to_string(),
+        }
+    }
+
+    /// Returns the parameter name, without the leading `$`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the `$name.path` expression, accessing a field of the bound value.
+    ///
+    /// ```
+    /// use surrealex::types::script::Param;
+    ///
+    /// assert_eq!(Param::new("user").field("email"), "$user.email");
+    /// ```
+    pub fn field(&self, path: &str) -> String {
+        format!("${}.{path}", self.name)
+    }
+}
+
+impl Display for Param {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "${}", self.name)
+    }
+}
+
+impl IntoTarget for Param {
+    fn into_target(self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoTarget for &Param {
+    fn into_target(self) -> String {
+        self.to_string()
+    }
+}
+
+/// Uses the bound value itself as a condition (e.g. `WHERE $is_admin`).
+impl From<Param> for Condition {
+    fn from(param: Param) -> Self {
+        Condition::Simple(param.to_string())
+    }
+}
+
+impl From<&Param> for Condition {
+    fn from(param: &Param) -> Self {
+        Condition::Simple(param.to_string())
+    }
+}
+
+/// Holds all the data needed to build a multi-statement script.
+#[derive(Default, Debug, Clone)]
+pub struct ScriptData {
+    /// The rendered statements, in order.
+    pub statements: Vec<String>,
+}
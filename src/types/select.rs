@@ -10,8 +10,18 @@ pub struct SelectData {
     pub only: bool,
     pub where_clause: Vec<Condition>,
     pub fetch_fields: Vec<String>,
+    /// When `true`, emits `SELECT VALUE`, returning the single projected
+    /// expression as a bare value instead of an object.
+    pub value: bool,
+    /// Fields in the `OMIT` clause, excluded from the projection. Ignored when
+    /// `value` is `true`.
+    pub omit: Vec<String>,
     pub order_by: Vec<String>,
     pub start_at: Option<u64>,
+    /// Fields in the `SPLIT` clause, each array value producing its own row.
+    pub split: Vec<String>,
+    /// Optional `GROUP BY @fields` / `GROUP ALL` clause.
+    pub group: Option<GroupClause>,
     /// Optional EXPLAIN mode (`EXPLAIN` or `EXPLAIN FULL`).
     pub explain: Option<ExplainClause>,
 }
@@ -22,6 +32,24 @@ pub struct SelectField {
     pub alias: Option<String>,
 }
 
+impl Display for SelectField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.alias {
+            Some(alias) => write!(f, "{} AS {}", self.name, alias),
+            None => write!(f, "{}", self.name),
+        }
+    }
+}
+
+/// Renders a projection as a comma-separated list of fields and aliases.
+pub(crate) fn render_fields(fields: &[SelectField]) -> String {
+    fields
+        .iter()
+        .map(|field| field.to_string())
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
 #[derive(Debug, Default)]
 pub struct OrderTerm {
     pub field: String,
@@ -194,3 +222,51 @@ impl From<()> for OrderOptions {
         OrderOptions::default()
     }
 }
+
+/// The grouping clause of a SELECT statement.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GroupClause {
+    /// `GROUP ALL`: aggregates over every matched record.
+    All,
+    /// `GROUP BY @field, ...`
+    By(Vec<String>),
+}
+
+impl Display for GroupClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GroupClause::All => write!(f, "GROUP ALL"),
+            GroupClause::By(fields) => write!(f, "GROUP BY {}", fields.join(", ")),
+        }
+    }
+}
+
+/// Errors detected while validating a SELECT statement.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SelectError {
+    /// The statement is grouped, but these projected fields are neither part
+    /// of the `GROUP BY` clause nor aggregate function calls.
+    UngroupedFields(Vec<String>),
+    /// The statement is a `SELECT VALUE`, but projects these expressions
+    /// instead of exactly one.
+    MultipleValueExpressions(Vec<String>),
+}
+
+impl Display for SelectError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SelectError::UngroupedFields(fields) => write!(
+                f,
+                "fields not present in GROUP BY and not aggregated: {}",
+                fields.join(", ")
+            ),
+            SelectError::MultipleValueExpressions(fields) => write!(
+                f,
+                "SELECT VALUE requires exactly one expression, found: {}",
+                fields.join(", ")
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SelectError {}
@@ -0,0 +1,32 @@
+use std::fmt::Display;
+
+use crate::internal_utils::quote_str;
+
+/// The starting point of a `SHOW CHANGES` query.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChangesSince {
+    /// `SINCE @versionstamp`
+    Versionstamp(u64),
+    /// `SINCE d"@datetime"`, as an ISO 8601 datetime string.
+    Datetime(String),
+}
+
+impl Display for ChangesSince {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChangesSince::Versionstamp(versionstamp) => write!(f, "{versionstamp}"),
+            ChangesSince::Datetime(datetime) => write!(f, "d{}", quote_str(datetime)),
+        }
+    }
+}
+
+/// Holds all the data needed to build a SHOW CHANGES statement.
+#[derive(Default, Debug, Clone)]
+pub struct ShowChangesData {
+    /// The table whose changefeed is read.
+    pub table: String,
+    /// The `SINCE` clause, set before the statement can be built.
+    pub since: Option<ChangesSince>,
+    /// Optional `LIMIT` clause.
+    pub limit: Option<u64>,
+}
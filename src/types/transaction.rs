@@ -0,0 +1,9 @@
+/// Holds all the data needed to build a transaction block.
+#[derive(Default, Debug, Clone)]
+pub struct TransactionData {
+    /// The rendered statements, in order.
+    pub statements: Vec<String>,
+    /// When `true`, the block ends with `CANCEL TRANSACTION` instead of
+    /// `COMMIT TRANSACTION`.
+    pub cancel: bool,
+}
@@ -0,0 +1,23 @@
+use crate::enums::{Condition, ReturnClause};
+use crate::types::create::ContentMode;
+
+/// Holds all the data needed to build an UPDATE statement.
+#[derive(Default, Debug, Clone)]
+pub struct UpdateData {
+    /// The target table or record id (e.g., `"person"`, `"person:tobie"`).
+    pub targets: String,
+    /// When `true`, emits `UPDATE ONLY` instead of `UPDATE`.
+    ///
+    /// **Note:** SurrealDB expects a single-result `RETURN` when using `ONLY`.
+    /// The builder does not enforce this — the server will validate it at runtime.
+    pub only: bool,
+    /// Optional data-setting mode (`CONTENT`, `SET`, `UNSET`, `MERGE`, `PATCH` or `REPLACE`).
+    pub content: Option<ContentMode>,
+    pub where_clause: Vec<Condition>,
+    /// Optional RETURN clause (`RETURN NONE | BEFORE | AFTER | DIFF | <params> | VALUE <param>`).
+    pub return_clause: Option<ReturnClause>,
+    /// Optional TIMEOUT duration as a raw SurrealQL duration string (e.g., `"2s"`, `"500ms"`).
+    pub timeout: Option<String>,
+    /// When `true`, emits the `PARALLEL` keyword.
+    pub parallel: bool,
+}
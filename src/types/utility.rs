@@ -0,0 +1,63 @@
+use std::fmt::Display;
+
+/// Holds all the data needed to build a USE statement.
+#[derive(Default, Debug, Clone)]
+pub struct UseData {
+    /// Optional `NS @namespace` part.
+    pub namespace: Option<String>,
+    /// Optional `DB @database` part.
+    pub database: Option<String>,
+}
+
+/// Holds all the data needed to build a SLEEP statement.
+#[derive(Default, Debug, Clone)]
+pub struct SleepData {
+    /// The sleep duration as a raw SurrealQL duration string (e.g., `"500ms"`).
+    pub duration: String,
+}
+
+/// Session options that can be toggled with an OPTION statement.
+#[derive(Debug, Clone, PartialEq)]
+pub enum QueryOption {
+    /// `IMPORT`: runs the following statements in import mode, skipping
+    /// events, table views and field processing.
+    Import,
+    /// `FIELDS`: controls whether field definitions are processed.
+    Fields,
+    /// `EVENTS`: controls whether table events are triggered.
+    Events,
+    /// `TABLES`: controls whether foreign table views are updated.
+    Tables,
+}
+
+impl Display for QueryOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QueryOption::Import => write!(f, "IMPORT"),
+            QueryOption::Fields => write!(f, "FIELDS"),
+            QueryOption::Events => write!(f, "EVENTS"),
+            QueryOption::Tables => write!(f, "TABLES"),
+        }
+    }
+}
+
+/// Holds all the data needed to build an OPTION statement.
+#[derive(Debug, Clone)]
+pub struct OptionData {
+    /// The toggled option.
+    pub option: QueryOption,
+    /// The option value. `true` renders the bare option name, `false` renders
+    /// `@option = false`.
+    pub enabled: bool,
+}
+
+/// Holds all the data needed to build a REBUILD INDEX statement.
+#[derive(Default, Debug, Clone)]
+pub struct RebuildIndexData {
+    /// The index name.
+    pub name: String,
+    /// The table the index is defined on.
+    pub table: String,
+    /// When `true`, emits `IF EXISTS` after `REBUILD INDEX`.
+    pub if_exists: bool,
+}
@@ -0,0 +1,153 @@
+use std::fmt::Write;
+
+use crate::{
+    SurrealV1, SurrealV2,
+    internal_macros::push_clause,
+    internal_utils::quote_str,
+    types::define::access::{AccessType, DefineAccessData, JwtVerify},
+    versioning::SurrealV3,
+};
+
+/// Trait for version-specific DEFINE ACCESS statement rendering behavior.
+///
+/// `DEFINE ACCESS` was introduced in SurrealDB 2.0 and replaces the
+/// `DEFINE SCOPE` and `DEFINE TOKEN` statements of SurrealDB 1.x:
+/// - V1 renders record access as `DEFINE SCOPE` (followed by a `DEFINE TOKEN ... ON SCOPE`
+///   statement when a JWT is configured) and JWT access as `DEFINE TOKEN`
+/// - V2 and V3 render `DEFINE ACCESS`
+pub trait VersionedAccess {
+    /// Renders the full access definition.
+    fn define_access(&self, data: &DefineAccessData) -> String {
+        define_access_statement(data)
+    }
+
+    /// Returns `true` when the definition renders as more than one statement.
+    fn is_multi_statement(&self, _data: &DefineAccessData) -> bool {
+        false
+    }
+}
+
+fn define_access_statement(data: &DefineAccessData) -> String {
+    let mut query = String::with_capacity(128);
+    push_clause!(query, "DEFINE ACCESS");
+
+    if let Some(ref mode) = data.mode {
+        push_clause!(query, "{mode}");
+    }
+
+    let name = &data.name;
+    let base = &data.base;
+    push_clause!(query, "{name} ON {base}");
+
+    match &data.access_type {
+        Some(AccessType::Record {
+            signup,
+            signin,
+            jwt,
+        }) => {
+            push_clause!(query, "TYPE RECORD");
+            if let Some(signup) = signup {
+                push_clause!(query, "SIGNUP ({signup})");
+            }
+            if let Some(signin) = signin {
+                push_clause!(query, "SIGNIN ({signin})");
+            }
+            if let Some(jwt) = jwt {
+                push_clause!(query, "WITH JWT {jwt}");
+            }
+        }
+        Some(AccessType::Jwt(jwt)) => push_clause!(query, "TYPE JWT {jwt}"),
+        Some(AccessType::Bearer(subject)) => push_clause!(query, "TYPE BEARER FOR {subject}"),
+        None => {}
+    }
+
+    if !data.durations.is_empty() {
+        let durations = &data.durations;
+        push_clause!(query, "DURATION {durations}");
+    }
+
+    if let Some(ref comment) = data.comment {
+        let comment = quote_str(comment);
+        push_clause!(query, "COMMENT {comment}");
+    }
+
+    query
+}
+
+/// Renders the V1 `TYPE @algorithm VALUE @key` form of a JWT verification.
+fn v1_token_type(jwt: &JwtVerify) -> String {
+    match jwt {
+        JwtVerify::Key { algorithm, key } => format!("TYPE {algorithm} VALUE {}", quote_str(key)),
+        JwtVerify::Url(url) => format!("TYPE JWKS VALUE {}", quote_str(url)),
+    }
+}
+
+impl VersionedAccess for SurrealV1 {
+    fn define_access(&self, data: &DefineAccessData) -> String {
+        let mut query = String::with_capacity(128);
+        let name = &data.name;
+
+        // JWT access becomes a `DEFINE TOKEN`; everything else becomes a
+        // `DEFINE SCOPE`. Bearer access has no V1 equivalent and the builder
+        // only exposes it for V2 and newer.
+        if let Some(AccessType::Jwt(jwt)) = &data.access_type {
+            push_clause!(query, "DEFINE TOKEN");
+            if let Some(ref mode) = data.mode {
+                push_clause!(query, "{mode}");
+            }
+            let base = &data.base;
+            let token_type = v1_token_type(jwt);
+            push_clause!(query, "{name} ON {base} {token_type}");
+            if let Some(ref comment) = data.comment {
+                let comment = quote_str(comment);
+                push_clause!(query, "COMMENT {comment}");
+            }
+            return query;
+        }
+
+        push_clause!(query, "DEFINE SCOPE");
+        if let Some(ref mode) = data.mode {
+            push_clause!(query, "{mode}");
+        }
+        push_clause!(query, "{name}");
+        if let Some(ref session) = data.durations.session {
+            push_clause!(query, "SESSION {session}");
+        }
+        let (signup, signin, jwt) = match &data.access_type {
+            Some(AccessType::Record {
+                signup,
+                signin,
+                jwt,
+            }) => (signup.as_ref(), signin.as_ref(), jwt.as_ref()),
+            _ => (None, None, None),
+        };
+        if let Some(signup) = signup {
+            push_clause!(query, "SIGNUP ({signup})");
+        }
+        if let Some(signin) = signin {
+            push_clause!(query, "SIGNIN ({signin})");
+        }
+        if let Some(ref comment) = data.comment {
+            let comment = quote_str(comment);
+            push_clause!(query, "COMMENT {comment}");
+        }
+        if let Some(jwt) = jwt {
+            query.push(';');
+            let token_type = v1_token_type(jwt);
+            push_clause!(query, "DEFINE TOKEN {name} ON SCOPE {name} {token_type}");
+        }
+
+        query
+    }
+
+    /// A record access with a JWT adds a separate `DEFINE TOKEN` statement.
+    fn is_multi_statement(&self, data: &DefineAccessData) -> bool {
+        matches!(
+            data.access_type,
+            Some(AccessType::Record { jwt: Some(_), .. })
+        )
+    }
+}
+
+impl VersionedAccess for SurrealV2 {}
+impl VersionedAccess for SurrealV3 {}
@@ -0,0 +1,54 @@
+use crate::{
+    SurrealV1, SurrealV2, enums::Condition, types::if_else::IfBody, versioning::SurrealV3,
+};
+
+/// Trait for version-specific IF / ELSE statement rendering behavior.
+///
+/// SurrealDB 2.0 uses block syntax (`IF @cond { .. } ELSE { .. }`), while
+/// SurrealDB 1.x uses the `IF @cond THEN (..) ELSE (..) END` form.
+pub trait VersionedIf {
+    /// Renders a guarded branch, following the `IF` / `ELSE IF` keyword.
+    fn guarded_branch(&self, condition: &Condition, body: &IfBody) -> String {
+        format!("{condition} {{ {} }}", body.statement)
+    }
+
+    /// Renders the body of the final `ELSE` branch.
+    fn else_branch(&self, body: &IfBody) -> String {
+        format!("{{ {} }}", body.statement)
+    }
+
+    /// Returns the keyword closing the statement, if any.
+    fn terminator(&self) -> Option<&'static str> {
+        None
+    }
+}
+
+/// Renders a V1 branch body. Single statements are wrapped in parentheses;
+/// multi-statement bodies become a `{ .. }` block, as parentheses only hold a
+/// single expression.
+fn v1_body(body: &IfBody) -> String {
+    if body.multi_statement {
+        format!("{{ {} }}", body.statement)
+    } else {
+        format!("({})", body.statement)
+    }
+}
+
+impl VersionedIf for SurrealV1 {
+    /// V1 introduces each branch with `THEN`.
+    fn guarded_branch(&self, condition: &Condition, body: &IfBody) -> String {
+        format!("{condition} THEN {}", v1_body(body))
+    }
+
+    fn else_branch(&self, body: &IfBody) -> String {
+        v1_body(body)
+    }
+
+    /// V1 closes the statement with `END`.
+    fn terminator(&self) -> Option<&'static str> {
+        Some("END")
+    }
+}
+
+impl VersionedIf for SurrealV2 {}
+impl VersionedIf for SurrealV3 {}
@@ -1,4 +1,8 @@
+pub mod access;
+pub mod if_else;
 pub mod select;
+pub mod update;
+pub mod upsert;
 
 /// SurrealDB v1
 #[derive(Debug, Clone, Copy, Default)]
@@ -11,3 +15,21 @@ pub struct SurrealV2;
 /// SurrealDB v3
 #[derive(Debug, Clone, Copy, Default)]
 pub struct SurrealV3;
+
+/// Marker trait for SurrealDB versions 2.0 and newer.
+///
+/// Builder methods for syntax introduced in SurrealDB 2.0 (e.g. `OVERWRITE`)
+/// are only available when the builder is bound to a version implementing this
+/// trait, so targeting [`SurrealV1`] turns their use into a compile error.
+pub trait SinceV2 {}
+
+impl SinceV2 for SurrealV2 {}
+impl SinceV2 for SurrealV3 {}
+
+/// Marker trait for SurrealDB versions 3.0 and newer.
+///
+/// Gates statements introduced in SurrealDB 3.0 (e.g. `ALTER TABLE`), in the
+/// same way [`SinceV2`] gates 2.0 syntax.
+pub trait SinceV3: SinceV2 {}
+
+impl SinceV3 for SurrealV3 {}
@@ -1,7 +1,7 @@
 use crate::{
     SurrealV1, SurrealV2,
     enums::SelectionFields,
-    types::select::{GraphTraversalParams, SelectData, SelectField},
+    types::select::{GraphTraversalParams, SelectData, SelectField, render_fields},
     versioning::SurrealV3,
 };
 
@@ -27,18 +27,7 @@ pub trait VersionedSelect {
         let fields = match params.fields {
             SelectionFields::All => "*".to_string(),
             SelectionFields::Fields(select_fields) => {
-                let joined = select_fields
-                    .iter()
-                    .map(|f| {
-                        if let Some(alias) = &f.alias {
-                            format!("{} AS {}", f.name, alias)
-                        } else {
-                            f.name.clone()
-                        }
-                    })
-                    .collect::<Vec<_>>()
-                    .join(", ");
-                format!("{{{}}}", joined)
+                format!("{{{}}}", render_fields(&select_fields))
             }
         };
 
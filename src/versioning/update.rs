@@ -0,0 +1,23 @@
+use crate::{SurrealV1, SurrealV2, types::create::ContentMode, versioning::SurrealV3};
+
+/// Trait for version-specific rendering of the data-setting clause shared by
+/// UPDATE and UPSERT statements.
+pub trait VersionedUpdate {
+    /// Renders the data-setting clause.
+    fn content(&self, content: &ContentMode) -> String {
+        content.to_string()
+    }
+}
+
+impl VersionedUpdate for SurrealV1 {
+    /// V1 has no `REPLACE` clause; `CONTENT` replaces the whole record instead.
+    fn content(&self, content: &ContentMode) -> String {
+        match content {
+            ContentMode::Replace(value) => ContentMode::Content(value.clone()).to_string(),
+            other => other.to_string(),
+        }
+    }
+}
+
+impl VersionedUpdate for SurrealV2 {}
+impl VersionedUpdate for SurrealV3 {}
@@ -0,0 +1,18 @@
+use crate::{
+    SurrealV2,
+    versioning::{SinceV2, SurrealV3, update::VersionedUpdate},
+};
+
+/// Trait for version-specific UPSERT statement rendering behavior.
+///
+/// `UPSERT` was introduced in SurrealDB 2.0 and has no equivalent in earlier
+/// versions: a 1.x `UPDATE` only creates missing records for specific record
+/// ids, never for table targets or `WHERE` filters. The trait is therefore only
+/// implemented for [`SinceV2`] versions.
+///
+/// The data-setting clause is rendered through [`VersionedUpdate`], so UPSERT
+/// and UPDATE handle `REPLACE` the same way.
+pub trait VersionedUpsert: VersionedUpdate + SinceV2 {}
+
+impl VersionedUpsert for SurrealV2 {}
+impl VersionedUpsert for SurrealV3 {}
@@ -0,0 +1,104 @@
+use surrealex::enums::{Base, Condition};
+use surrealex::types::access::PurgeTarget;
+use surrealex::{QueryBuilder, SurrealV2, SurrealV3};
+
+#[test]
+fn grant_for_user_and_record() {
+    assert_eq!(
+        QueryBuilder::access("api").grant_for_user("ci").build(),
+        "ACCESS api GRANT FOR USER ci"
+    );
+    assert_eq!(
+        QueryBuilder::access("api")
+            .on(Base::Database)
+            .grant_for_record("user:tobie")
+            .build(),
+        "ACCESS api ON DATABASE GRANT FOR RECORD user:tobie"
+    );
+}
+
+#[test]
+fn show_grants() {
+    assert_eq!(
+        QueryBuilder::access("api").show_all().build(),
+        "ACCESS api SHOW ALL"
+    );
+    assert_eq!(
+        QueryBuilder::access("api")
+            .show_grant("lzhz8jrilx3q")
+            .build(),
+        "ACCESS api SHOW GRANT lzhz8jrilx3q"
+    );
+    assert_eq!(
+        QueryBuilder::access("api")
+            .on(Base::Namespace)
+            .show_where(Condition::new("subject.user = 'ci'"))
+            .build(),
+        "ACCESS api ON NAMESPACE SHOW WHERE subject.user = 'ci'"
+    );
+}
+
+#[test]
+fn revoke_grants() {
+    assert_eq!(
+        QueryBuilder::access("api").revoke_all().build(),
+        "ACCESS api REVOKE ALL"
+    );
+    assert_eq!(
+        QueryBuilder::access("api")
+            .revoke_grant("lzhz8jrilx3q")
+            .build(),
+        "ACCESS api REVOKE GRANT lzhz8jrilx3q"
+    );
+    assert_eq!(
+        QueryBuilder::access("api")
+            .revoke_where(Condition::new("creation < time::now() - 30d").and("revocation IS NONE"))
+            .build(),
+        "ACCESS api REVOKE WHERE (creation < time::now() - 30d AND revocation IS NONE)"
+    );
+}
+
+#[test]
+fn purge_grants() {
+    assert_eq!(
+        QueryBuilder::access("api")
+            .purge(PurgeTarget::Expired)
+            .build(),
+        "ACCESS api PURGE EXPIRED"
+    );
+    assert_eq!(
+        QueryBuilder::access("api")
+            .on(Base::Database)
+            .purge(PurgeTarget::ExpiredAndRevoked)
+            .grace("90d")
+            .build(),
+        "ACCESS api ON DATABASE PURGE EXPIRED, REVOKED FOR 90d"
+    );
+}
+
+#[test]
+fn key_rotation_script() {
+    let sql = QueryBuilder::script()
+        .statement(QueryBuilder::access("api").revoke_where("subject.user = 'ci'"))
+        .statement(QueryBuilder::access("api").grant_for_user("ci"))
+        .build();
+    assert_eq!(
+        sql,
+        "ACCESS api REVOKE WHERE subject.user = 'ci'; ACCESS api GRANT FOR USER ci"
+    );
+}
+
+#[test]
+fn versioned_builder_renders_access() {
+    let sql = QueryBuilder::with_version(SurrealV2)
+        .access("api")
+        .show_all()
+        .build();
+    assert_eq!(sql, "ACCESS api SHOW ALL");
+
+    let sql = QueryBuilder::with_version(SurrealV3)
+        .access("api")
+        .purge(PurgeTarget::Revoked)
+        .build();
+    assert_eq!(sql, "ACCESS api PURGE REVOKED");
+}
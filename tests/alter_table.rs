@@ -0,0 +1,66 @@
+use std::time::Duration;
+
+use surrealex::types::define::{Permission, PermissionKind};
+use surrealex::{QueryBuilder, SurrealV3};
+
+#[test]
+fn build_alter_table() {
+    let sql = QueryBuilder::with_version(SurrealV3)
+        .alter_table("person")
+        .build();
+    assert_eq!(sql, "ALTER TABLE person");
+}
+
+#[test]
+fn if_exists_and_schema_mode() {
+    let sql = QueryBuilder::with_version(SurrealV3)
+        .alter_table("person")
+        .if_exists()
+        .schemaless()
+        .build();
+    assert_eq!(sql, "ALTER TABLE IF EXISTS person SCHEMALESS");
+}
+
+#[test]
+fn changefeed_with_include_original() {
+    let sql = QueryBuilder::with_version(SurrealV3)
+        .alter_table("person")
+        .changefeed(Duration::from_secs(86_400))
+        .include_original()
+        .build();
+    assert_eq!(sql, "ALTER TABLE person CHANGEFEED 1d INCLUDE ORIGINAL");
+}
+
+#[test]
+fn permissions_variants() {
+    let sql = QueryBuilder::with_version(SurrealV3)
+        .alter_table("person")
+        .permissions_none()
+        .build();
+    assert_eq!(sql, "ALTER TABLE person PERMISSIONS NONE");
+
+    let sql = QueryBuilder::with_version(SurrealV3)
+        .alter_table("person")
+        .permissions_for(vec![PermissionKind::Select], Permission::Full)
+        .permissions_for(vec![PermissionKind::Delete], Permission::None)
+        .build();
+    assert_eq!(
+        sql,
+        "ALTER TABLE person PERMISSIONS FOR select FULL FOR delete NONE"
+    );
+}
+
+#[test]
+fn all_properties() {
+    let sql = QueryBuilder::with_version(SurrealV3)
+        .alter_table("post")
+        .comment("blog posts")
+        .permissions_full()
+        .changefeed("3d")
+        .schemafull()
+        .build();
+    assert_eq!(
+        sql,
+        "ALTER TABLE post SCHEMAFULL CHANGEFEED 3d PERMISSIONS FULL COMMENT \"blog posts\""
+    );
+}
@@ -0,0 +1,271 @@
+use std::time::Duration;
+
+use surrealex::enums::{Base, SelectionFields};
+use surrealex::types::define::access::{BearerSubject, JwtAlgorithm, JwtVerify, TokenBase};
+use surrealex::types::define::user::Role;
+use surrealex::{QueryBuilder, SurrealV1, SurrealV2};
+
+#[test]
+fn build_record_access() {
+    let sql = QueryBuilder::define_access("account").type_record().build();
+    assert_eq!(sql, "DEFINE ACCESS account ON DATABASE TYPE RECORD");
+}
+
+#[test]
+fn record_access_with_signup_and_signin() {
+    let sql = QueryBuilder::define_access("account")
+        .signup(QueryBuilder::create("user").set("email", "$email"))
+        .signin(
+            QueryBuilder::select(SelectionFields::All)
+                .from("user")
+                .r#where("email = $email"),
+        )
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE ACCESS account ON DATABASE TYPE RECORD SIGNUP (CREATE user SET email = $email) SIGNIN (SELECT * FROM user WHERE email = $email)"
+    );
+}
+
+#[test]
+fn record_access_with_jwt() {
+    let sql = QueryBuilder::define_access("account")
+        .signin("SELECT * FROM user WHERE email = $email")
+        .with_jwt(JwtVerify::key(JwtAlgorithm::Hs512, "secret"))
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE ACCESS account ON DATABASE TYPE RECORD SIGNIN (SELECT * FROM user WHERE email = $email) WITH JWT ALGORITHM HS512 KEY \"secret\""
+    );
+}
+
+#[test]
+fn jwt_access_with_key() {
+    let sql = QueryBuilder::define_access("token")
+        .on(Base::Namespace)
+        .type_jwt(JwtVerify::key(
+            JwtAlgorithm::Rs256,
+            "-----BEGIN PUBLIC KEY-----",
+        ))
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE ACCESS token ON NAMESPACE TYPE JWT ALGORITHM RS256 KEY \"-----BEGIN PUBLIC KEY-----\""
+    );
+}
+
+#[test]
+fn jwt_access_with_url() {
+    let sql = QueryBuilder::define_access("token")
+        .type_jwt(JwtVerify::url("https://example.com/.well-known/jwks.json"))
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE ACCESS token ON DATABASE TYPE JWT URL \"https://example.com/.well-known/jwks.json\""
+    );
+}
+
+#[test]
+fn bearer_access() {
+    let sql = QueryBuilder::define_access("api")
+        .type_bearer(BearerSubject::User)
+        .duration_grant("30d")
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE ACCESS api ON DATABASE TYPE BEARER FOR USER DURATION FOR GRANT 30d"
+    );
+}
+
+#[test]
+fn all_durations_and_comment() {
+    let sql = QueryBuilder::define_access("account")
+        .type_record()
+        .duration_grant("30d")
+        .duration_token(Duration::from_secs(900))
+        .duration_session("12h")
+        .comment("user accounts")
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE ACCESS account ON DATABASE TYPE RECORD DURATION FOR GRANT 30d, FOR TOKEN 15m, FOR SESSION 12h COMMENT \"user accounts\""
+    );
+}
+
+#[test]
+fn overwrite_and_if_not_exists() {
+    let sql = QueryBuilder::define_access("account")
+        .overwrite()
+        .type_record()
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE ACCESS OVERWRITE account ON DATABASE TYPE RECORD"
+    );
+
+    let sql = QueryBuilder::define_access("account")
+        .overwrite()
+        .if_not_exists()
+        .type_record()
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE ACCESS IF NOT EXISTS account ON DATABASE TYPE RECORD"
+    );
+}
+
+#[test]
+fn type_jwt_replaces_record_type() {
+    let sql = QueryBuilder::define_access("token")
+        .signin("SELECT * FROM user")
+        .type_jwt(JwtVerify::url("https://example.com/jwks"))
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE ACCESS token ON DATABASE TYPE JWT URL \"https://example.com/jwks\""
+    );
+}
+
+#[test]
+fn v1_record_access_renders_define_scope() {
+    let sql = QueryBuilder::with_version(SurrealV1)
+        .define_access("account")
+        .signup("CREATE user SET email = $email")
+        .signin("SELECT * FROM user WHERE email = $email")
+        .duration_session("24h")
+        .comment("accounts")
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE SCOPE account SESSION 24h SIGNUP (CREATE user SET email = $email) SIGNIN (SELECT * FROM user WHERE email = $email) COMMENT \"accounts\""
+    );
+}
+
+#[test]
+fn v1_record_access_with_jwt_adds_scope_token() {
+    let sql = QueryBuilder::with_version(SurrealV1)
+        .define_access("account")
+        .type_record()
+        .with_jwt(JwtVerify::key(JwtAlgorithm::Hs512, "secret"))
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE SCOPE account; DEFINE TOKEN account ON SCOPE account TYPE HS512 VALUE \"secret\""
+    );
+}
+
+#[test]
+fn v1_scope_token_renders_as_block_in_if_body() {
+    let access = QueryBuilder::with_version(SurrealV1)
+        .define_access("account")
+        .type_record()
+        .with_jwt(JwtVerify::key(JwtAlgorithm::Hs512, "secret"));
+    let sql = QueryBuilder::with_version(SurrealV1)
+        .r#if("$setup", access)
+        .build();
+    assert_eq!(
+        sql,
+        "IF $setup THEN { DEFINE SCOPE account; DEFINE TOKEN account ON SCOPE account TYPE HS512 VALUE \"secret\" } END"
+    );
+
+    let scope = QueryBuilder::with_version(SurrealV1)
+        .define_access("account")
+        .type_record();
+    let sql = QueryBuilder::with_version(SurrealV1)
+        .r#if("$setup", scope)
+        .build();
+    assert_eq!(sql, "IF $setup THEN (DEFINE SCOPE account) END");
+}
+
+#[test]
+fn v1_jwt_access_renders_define_token() {
+    let sql = QueryBuilder::with_version(SurrealV1)
+        .define_access("token")
+        .on(TokenBase::Namespace)
+        .type_jwt(JwtVerify::key(JwtAlgorithm::Hs256, "secret"))
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE TOKEN token ON NAMESPACE TYPE HS256 VALUE \"secret\""
+    );
+
+    let sql = QueryBuilder::with_version(SurrealV1)
+        .define_access("token")
+        .type_jwt(JwtVerify::url("https://example.com/jwks"))
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE TOKEN token ON DATABASE TYPE JWKS VALUE \"https://example.com/jwks\""
+    );
+}
+
+#[test]
+fn v1_scope_ignores_token_base() {
+    let sql = QueryBuilder::with_version(SurrealV1)
+        .define_access("account")
+        .on(TokenBase::Namespace)
+        .type_record()
+        .build();
+    assert_eq!(sql, "DEFINE SCOPE account");
+}
+
+#[test]
+fn versioned_builder_renders_define_access() {
+    let sql = QueryBuilder::with_version(SurrealV2)
+        .define_access("account")
+        .type_record()
+        .build();
+    assert_eq!(sql, "DEFINE ACCESS account ON DATABASE TYPE RECORD");
+}
+
+#[test]
+fn build_define_user() {
+    let sql = QueryBuilder::define_user("admin")
+        .on(Base::Root)
+        .password("s3cret")
+        .role(Role::Owner)
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE USER admin ON ROOT PASSWORD \"s3cret\" ROLES OWNER"
+    );
+}
+
+#[test]
+fn user_with_passhash_roles_durations_and_comment() {
+    let sql = QueryBuilder::define_user("reader")
+        .if_not_exists()
+        .passhash("$argon2id$v=19$m=19456")
+        .roles(vec![Role::Editor, Role::Viewer])
+        .duration_token("5m")
+        .duration_session("1h")
+        .comment("read \"only\"")
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE USER IF NOT EXISTS reader ON DATABASE PASSHASH \"$argon2id$v=19$m=19456\" ROLES EDITOR, VIEWER DURATION FOR TOKEN 5m, FOR SESSION 1h COMMENT \"read \\\"only\\\"\""
+    );
+}
+
+#[test]
+fn password_replaces_passhash() {
+    let sql = QueryBuilder::define_user("admin")
+        .passhash("hash")
+        .password("plain")
+        .build();
+    assert_eq!(sql, "DEFINE USER admin ON DATABASE PASSWORD \"plain\"");
+}
+
+#[test]
+fn versioned_builder_renders_define_user() {
+    let sql = QueryBuilder::with_version(SurrealV1)
+        .define_user("admin")
+        .on(Base::Namespace)
+        .password("s3cret")
+        .role(Role::Editor)
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE USER admin ON NAMESPACE PASSWORD \"s3cret\" ROLES EDITOR"
+    );
+}
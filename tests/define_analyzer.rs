@@ -0,0 +1,100 @@
+use surrealex::types::define::analyzer::{AnalyzerFilter, Language, Tokenizer};
+use surrealex::{QueryBuilder, SurrealV1};
+
+#[test]
+fn build_bare_analyzer() {
+    let sql = QueryBuilder::define_analyzer("simple").build();
+    assert_eq!(sql, "DEFINE ANALYZER simple");
+}
+
+#[test]
+fn all_tokenizers() {
+    let sql = QueryBuilder::define_analyzer("code")
+        .tokenizers(vec![
+            Tokenizer::Blank,
+            Tokenizer::Camel,
+            Tokenizer::Class,
+            Tokenizer::Punct,
+        ])
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE ANALYZER code TOKENIZERS blank,camel,class,punct"
+    );
+}
+
+#[test]
+fn simple_filters() {
+    let sql = QueryBuilder::define_analyzer("folding")
+        .tokenizers(vec![Tokenizer::Blank])
+        .filters(vec![
+            AnalyzerFilter::Ascii,
+            AnalyzerFilter::Lowercase,
+            AnalyzerFilter::Uppercase,
+        ])
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE ANALYZER folding TOKENIZERS blank FILTERS ascii,lowercase,uppercase"
+    );
+}
+
+#[test]
+fn parameterized_filters() {
+    let sql = QueryBuilder::define_analyzer("autocomplete")
+        .tokenizers(vec![Tokenizer::Class])
+        .filters(vec![
+            AnalyzerFilter::edgengram(2, 10),
+            AnalyzerFilter::ngram(1, 3),
+            AnalyzerFilter::snowball(Language::German),
+            AnalyzerFilter::mapper("mappers/synonyms.txt"),
+        ])
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE ANALYZER autocomplete TOKENIZERS class FILTERS edgengram(2,10),ngram(1,3),snowball(german),mapper(\"mappers/synonyms.txt\")"
+    );
+}
+
+#[test]
+fn function_mode_and_comment() {
+    let sql = QueryBuilder::define_analyzer("stripped")
+        .overwrite()
+        .function("fn::strip_html")
+        .tokenizers(vec![Tokenizer::Blank])
+        .filters(vec![AnalyzerFilter::Lowercase])
+        .comment("strips html")
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE ANALYZER OVERWRITE stripped FUNCTION fn::strip_html TOKENIZERS blank FILTERS lowercase COMMENT \"strips html\""
+    );
+}
+
+#[test]
+fn repeated_calls_accumulate() {
+    let sql = QueryBuilder::define_analyzer("english")
+        .if_not_exists()
+        .tokenizers(vec![Tokenizer::Blank])
+        .tokenizers(vec![Tokenizer::Punct])
+        .filters(vec![AnalyzerFilter::Lowercase])
+        .filters(vec![AnalyzerFilter::snowball(Language::English)])
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE ANALYZER IF NOT EXISTS english TOKENIZERS blank,punct FILTERS lowercase,snowball(english)"
+    );
+}
+
+#[test]
+fn versioned_builder_renders_define_analyzer() {
+    let sql = QueryBuilder::with_version(SurrealV1)
+        .define_analyzer("english")
+        .tokenizers(vec![Tokenizer::Blank])
+        .filters(vec![AnalyzerFilter::snowball(Language::English)])
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE ANALYZER english TOKENIZERS blank FILTERS snowball(english)"
+    );
+}
@@ -0,0 +1,39 @@
+use std::time::Duration;
+
+use surrealex::{QueryBuilder, SurrealV1};
+
+#[test]
+fn build_define_database() {
+    let sql = QueryBuilder::define_database("app").build();
+    assert_eq!(sql, "DEFINE DATABASE app");
+}
+
+#[test]
+fn database_with_changefeed() {
+    let sql = QueryBuilder::define_database("app")
+        .changefeed(Duration::from_secs(3 * 86_400))
+        .build();
+    assert_eq!(sql, "DEFINE DATABASE app CHANGEFEED 3d");
+}
+
+#[test]
+fn database_with_mode_comment_and_changefeed() {
+    let sql = QueryBuilder::define_database("app")
+        .overwrite()
+        .comment("main database")
+        .changefeed("1h")
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE DATABASE OVERWRITE app COMMENT \"main database\" CHANGEFEED 1h"
+    );
+}
+
+#[test]
+fn versioned_builder_renders_define_database() {
+    let sql = QueryBuilder::with_version(SurrealV1)
+        .define_database("app")
+        .changefeed("7d")
+        .build();
+    assert_eq!(sql, "DEFINE DATABASE app CHANGEFEED 7d");
+}
@@ -0,0 +1,182 @@
+use surrealex::enums::Condition;
+use surrealex::traits::IntoStatement;
+use surrealex::types::define::event::{self, EventType};
+use surrealex::{QueryBuilder, SurrealV1};
+
+#[test]
+fn build_define_event_with_raw_statement() {
+    let sql = QueryBuilder::define_event("audit", "user")
+        .then("CREATE log SET at = time::now()")
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE EVENT audit ON TABLE user THEN { CREATE log SET at = time::now() }"
+    );
+}
+
+#[test]
+fn overwrite_and_if_not_exists() {
+    let sql = QueryBuilder::define_event("audit", "user")
+        .overwrite()
+        .then("CREATE log")
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE EVENT OVERWRITE audit ON TABLE user THEN { CREATE log }"
+    );
+
+    let sql = QueryBuilder::define_event("audit", "user")
+        .if_not_exists()
+        .then("CREATE log")
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE EVENT IF NOT EXISTS audit ON TABLE user THEN { CREATE log }"
+    );
+}
+
+#[test]
+fn event_type_renders_event_condition() {
+    assert_eq!(
+        Condition::from(EventType::Create).to_string(),
+        "$event = \"CREATE\""
+    );
+    assert_eq!(
+        Condition::from(EventType::Update).to_string(),
+        "$event = \"UPDATE\""
+    );
+    assert_eq!(
+        Condition::from(EventType::Delete).to_string(),
+        "$event = \"DELETE\""
+    );
+}
+
+#[test]
+fn before_and_after_helpers() {
+    assert_eq!(event::before("email"), "$before.email");
+    assert_eq!(event::after("address.city"), "$after.address.city");
+    assert_eq!(
+        event::changed("email").to_string(),
+        "$before.email != $after.email"
+    );
+}
+
+#[test]
+fn when_conditions_are_joined_with_and() {
+    let sql = QueryBuilder::define_event("email", "user")
+        .when(EventType::Update)
+        .when(event::changed("email"))
+        .then("CREATE log")
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE EVENT email ON TABLE user WHEN $event = \"UPDATE\" AND $before.email != $after.email THEN { CREATE log }"
+    );
+}
+
+#[test]
+fn when_accepts_nested_conditions() {
+    let sql = QueryBuilder::define_event("changes", "user")
+        .when(Condition::from(EventType::Create).or(EventType::Delete))
+        .then("CREATE log")
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE EVENT changes ON TABLE user WHEN ($event = \"CREATE\" OR $event = \"DELETE\") THEN { CREATE log }"
+    );
+}
+
+#[test]
+fn then_accepts_create_builder() {
+    let sql = QueryBuilder::define_event("signup", "user")
+        .when(EventType::Create)
+        .then(
+            QueryBuilder::create("notification")
+                .set("user", "$after.id")
+                .set("kind", "'welcome'"),
+        )
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE EVENT signup ON TABLE user WHEN $event = \"CREATE\" THEN { CREATE notification SET user = $after.id, kind = 'welcome' }"
+    );
+}
+
+#[test]
+fn then_accepts_multiple_builders() {
+    let sql = QueryBuilder::define_event("cleanup", "user")
+        .when(EventType::Delete)
+        .then(QueryBuilder::delete("post").r#where("author = $before.id"))
+        .then(QueryBuilder::insert("archive").content("{ user: $before.id, at: time::now() }"))
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE EVENT cleanup ON TABLE user WHEN $event = \"DELETE\" THEN { DELETE FROM post WHERE author = $before.id; INSERT INTO archive { user: $before.id, at: time::now() } }"
+    );
+}
+
+#[test]
+fn then_accepts_select_subquery() {
+    let sql = QueryBuilder::define_event("touch", "post")
+        .then(
+            QueryBuilder::select(surrealex::fields!("id"))
+                .from("user")
+                .r#where("id = $after.author"),
+        )
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE EVENT touch ON TABLE post THEN { SELECT id FROM user WHERE id = $after.author }"
+    );
+}
+
+#[test]
+fn then_accepts_update_and_relate_builders() {
+    let sql = QueryBuilder::define_event("publish", "post")
+        .when(EventType::Create)
+        .then(QueryBuilder::update("$after.author").set("posts", "posts + 1"))
+        .then(QueryBuilder::relate("$after.author", "wrote", "$after.id"))
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE EVENT publish ON TABLE post WHEN $event = \"CREATE\" THEN { UPDATE $after.author SET posts = posts + 1; RELATE $after.author->wrote->$after.id }"
+    );
+}
+
+#[test]
+fn comment_clause() {
+    let sql = QueryBuilder::define_event("audit", "user")
+        .comment("Audit trail")
+        .then("CREATE log")
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE EVENT audit ON TABLE user THEN { CREATE log } COMMENT \"Audit trail\""
+    );
+}
+
+#[test]
+fn builders_implement_into_statement() {
+    assert_eq!(
+        QueryBuilder::create("person").into_statement(),
+        "CREATE person"
+    );
+    assert_eq!(
+        QueryBuilder::define_table("person").into_statement(),
+        "DEFINE TABLE person"
+    );
+    assert_eq!(String::from("RETURN 1").into_statement(), "RETURN 1");
+}
+
+#[test]
+fn versioned_builder_renders_define_event() {
+    let sql = QueryBuilder::with_version(SurrealV1)
+        .define_event("audit", "user")
+        .when(EventType::Create)
+        .then("CREATE log")
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE EVENT audit ON TABLE user WHEN $event = \"CREATE\" THEN { CREATE log }"
+    );
+}
@@ -0,0 +1,289 @@
+use surrealex::enums::Condition;
+use surrealex::types::define::kind::{GeometryKind, Kind};
+use surrealex::types::define::{Permission, field::FieldPermissionKind};
+use surrealex::{QueryBuilder, SurrealV1};
+
+#[test]
+fn build_define_field() {
+    let sql = QueryBuilder::define_field("email", "user").build();
+    assert_eq!(sql, "DEFINE FIELD email ON TABLE user");
+}
+
+#[test]
+fn overwrite_and_if_not_exists() {
+    let sql = QueryBuilder::define_field("email", "user")
+        .overwrite()
+        .build();
+    assert_eq!(sql, "DEFINE FIELD OVERWRITE email ON TABLE user");
+
+    let sql = QueryBuilder::define_field("email", "user")
+        .if_not_exists()
+        .build();
+    assert_eq!(sql, "DEFINE FIELD IF NOT EXISTS email ON TABLE user");
+}
+
+#[test]
+fn scalar_types() {
+    let cases = [
+        (Kind::Any, "any"),
+        (Kind::Null, "null"),
+        (Kind::Bool, "bool"),
+        (Kind::Bytes, "bytes"),
+        (Kind::Datetime, "datetime"),
+        (Kind::Decimal, "decimal"),
+        (Kind::Duration, "duration"),
+        (Kind::Float, "float"),
+        (Kind::Int, "int"),
+        (Kind::Number, "number"),
+        (Kind::Object, "object"),
+        (Kind::String, "string"),
+        (Kind::Uuid, "uuid"),
+        (Kind::Range, "range"),
+        (Kind::Function, "function"),
+    ];
+
+    for (kind, expected) in cases {
+        let sql = QueryBuilder::define_field("f", "t").r#type(kind).build();
+        assert_eq!(sql, format!("DEFINE FIELD f ON TABLE t TYPE {expected}"));
+    }
+}
+
+#[test]
+fn option_type() {
+    let sql = QueryBuilder::define_field("nickname", "user")
+        .r#type(Kind::option(Kind::String))
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE FIELD nickname ON TABLE user TYPE option<string>"
+    );
+}
+
+#[test]
+fn array_types() {
+    assert_eq!(Kind::array(Kind::Any).to_string(), "array");
+    assert_eq!(Kind::array(Kind::String).to_string(), "array<string>");
+    assert_eq!(Kind::array_max(Kind::Int, 3).to_string(), "array<int, 3>");
+    assert_eq!(Kind::array_max(Kind::Any, 3).to_string(), "array<any, 3>");
+}
+
+#[test]
+fn set_types() {
+    assert_eq!(Kind::set(Kind::Any).to_string(), "set");
+    assert_eq!(Kind::set(Kind::Uuid).to_string(), "set<uuid>");
+    assert_eq!(Kind::set_max(Kind::String, 5).to_string(), "set<string, 5>");
+}
+
+#[test]
+fn record_types() {
+    assert_eq!(Kind::record(Vec::<&str>::new()).to_string(), "record");
+    assert_eq!(Kind::record(vec!["user"]).to_string(), "record<user>");
+    assert_eq!(
+        Kind::record(vec!["user", "admin"]).to_string(),
+        "record<user | admin>"
+    );
+}
+
+#[test]
+fn geometry_types() {
+    assert_eq!(Kind::geometry(vec![]).to_string(), "geometry");
+    assert_eq!(
+        Kind::geometry(vec![GeometryKind::Point]).to_string(),
+        "geometry<point>"
+    );
+    assert_eq!(
+        Kind::geometry(vec![
+            GeometryKind::Feature,
+            GeometryKind::Line,
+            GeometryKind::Polygon,
+            GeometryKind::MultiPoint,
+            GeometryKind::MultiLine,
+            GeometryKind::MultiPolygon,
+            GeometryKind::Collection,
+        ])
+        .to_string(),
+        "geometry<feature | line | polygon | multipoint | multiline | multipolygon | collection>"
+    );
+}
+
+#[test]
+fn literal_union_type() {
+    let sql = QueryBuilder::define_field("status", "order")
+        .r#type(Kind::either(vec![
+            Kind::string_literal("pending"),
+            Kind::string_literal("shipped"),
+        ]))
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE FIELD status ON TABLE order TYPE \"pending\" | \"shipped\""
+    );
+}
+
+#[test]
+fn nested_composite_types() {
+    let kind = Kind::option(Kind::array(Kind::either(vec![
+        Kind::record(vec!["user"]),
+        Kind::literal("1"),
+    ])));
+    assert_eq!(kind.to_string(), "option<array<record<user> | 1>>");
+}
+
+#[test]
+fn flexible_type() {
+    let sql = QueryBuilder::define_field("metadata", "user")
+        .flexible()
+        .r#type(Kind::Object)
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE FIELD metadata ON TABLE user FLEXIBLE TYPE object"
+    );
+}
+
+#[test]
+fn default_value() {
+    let sql = QueryBuilder::define_field("created", "user")
+        .r#type(Kind::Datetime)
+        .default("time::now()")
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE FIELD created ON TABLE user TYPE datetime DEFAULT time::now()"
+    );
+}
+
+#[test]
+fn default_always_value() {
+    let sql = QueryBuilder::define_field("updated", "user")
+        .default_always("time::now()")
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE FIELD updated ON TABLE user DEFAULT ALWAYS time::now()"
+    );
+}
+
+#[test]
+fn calling_default_after_default_always_uses_last_value() {
+    let sql = QueryBuilder::define_field("role", "user")
+        .default_always("'admin'")
+        .default("'guest'")
+        .build();
+    assert_eq!(sql, "DEFINE FIELD role ON TABLE user DEFAULT 'guest'");
+}
+
+#[test]
+fn readonly_field() {
+    let sql = QueryBuilder::define_field("created", "user")
+        .readonly()
+        .build();
+    assert_eq!(sql, "DEFINE FIELD created ON TABLE user READONLY");
+}
+
+#[test]
+fn value_expression() {
+    let sql = QueryBuilder::define_field("email", "user")
+        .value("string::lowercase($value)")
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE FIELD email ON TABLE user VALUE string::lowercase($value)"
+    );
+}
+
+#[test]
+fn assert_conditions_are_joined_with_and() {
+    let sql = QueryBuilder::define_field("age", "user")
+        .assert("$value >= 0")
+        .assert(Condition::new("$value <= 150").or("$value = NONE"))
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE FIELD age ON TABLE user ASSERT $value >= 0 AND ($value <= 150 OR $value = NONE)"
+    );
+}
+
+#[test]
+fn per_operation_permissions() {
+    let sql = QueryBuilder::define_field("email", "user")
+        .permissions_for(vec![FieldPermissionKind::Select], "id = $auth.id")
+        .permissions_for(vec![FieldPermissionKind::Update], Permission::None)
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE FIELD email ON TABLE user PERMISSIONS FOR select WHERE id = $auth.id FOR update NONE"
+    );
+}
+
+#[test]
+fn permissions_none_and_full() {
+    let sql = QueryBuilder::define_field("secret", "user")
+        .permissions_none()
+        .build();
+    assert_eq!(sql, "DEFINE FIELD secret ON TABLE user PERMISSIONS NONE");
+
+    let sql = QueryBuilder::define_field("name", "user")
+        .permissions_full()
+        .build();
+    assert_eq!(sql, "DEFINE FIELD name ON TABLE user PERMISSIONS FULL");
+}
+
+#[test]
+fn comment_clause() {
+    let sql = QueryBuilder::define_field("email", "user")
+        .comment("Primary contact")
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE FIELD email ON TABLE user COMMENT \"Primary contact\""
+    );
+}
+
+#[test]
+fn all_clauses_combined() {
+    let sql = QueryBuilder::define_field("email", "user")
+        .overwrite()
+        .r#type(Kind::String)
+        .default("''")
+        .readonly()
+        .value("string::lowercase($value)")
+        .assert("string::is::email($value)")
+        .permissions_full()
+        .comment("Login email")
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE FIELD OVERWRITE email ON TABLE user TYPE string DEFAULT '' READONLY VALUE string::lowercase($value) ASSERT string::is::email($value) PERMISSIONS FULL COMMENT \"Login email\""
+    );
+}
+
+#[test]
+fn clauses_are_emitted_in_correct_order_regardless_of_call_order() {
+    let sql = QueryBuilder::define_field("tags", "post")
+        .comment("Tags")
+        .assert("array::len($value) < 10")
+        .value("array::distinct($value)")
+        .readonly()
+        .default("[]")
+        .r#type(Kind::array(Kind::String))
+        .flexible()
+        .if_not_exists()
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE FIELD IF NOT EXISTS tags ON TABLE post FLEXIBLE TYPE array<string> DEFAULT [] READONLY VALUE array::distinct($value) ASSERT array::len($value) < 10 COMMENT \"Tags\""
+    );
+}
+
+#[test]
+fn versioned_builder_renders_define_field() {
+    let sql = QueryBuilder::with_version(SurrealV1)
+        .define_field("location", "place")
+        .r#type(Kind::geometry(vec![GeometryKind::Point]))
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE FIELD location ON TABLE place TYPE geometry<point>"
+    );
+}
@@ -0,0 +1,187 @@
+use surrealex::enums::Condition;
+use surrealex::types::define::function::FunctionCall;
+use surrealex::types::define::kind::Kind;
+use surrealex::{QueryBuilder, SurrealV1};
+
+#[test]
+fn build_define_function_without_arguments() {
+    let sql = QueryBuilder::define_function("fn::now")
+        .r#return("time::now()")
+        .build();
+    assert_eq!(sql, "DEFINE FUNCTION fn::now() { RETURN time::now() }");
+}
+
+#[test]
+fn empty_body_renders_empty_block() {
+    let sql = QueryBuilder::define_function("fn::noop").build();
+    assert_eq!(sql, "DEFINE FUNCTION fn::noop() {}");
+}
+
+#[test]
+fn typed_arguments() {
+    let sql = QueryBuilder::define_function("fn::greet")
+        .arg("name", Kind::String)
+        .arg("$times", Kind::option(Kind::Int))
+        .r#return("$name")
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE FUNCTION fn::greet($name: string, $times: option<int>) { RETURN $name }"
+    );
+}
+
+#[test]
+fn return_type() {
+    let sql = QueryBuilder::define_function("fn::double")
+        .arg("n", Kind::Number)
+        .returns(Kind::Number)
+        .r#return("$n * 2")
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE FUNCTION fn::double($n: number) -> number { RETURN $n * 2 }"
+    );
+}
+
+#[test]
+fn overwrite_and_if_not_exists() {
+    let sql = QueryBuilder::define_function("fn::one")
+        .overwrite()
+        .r#return("1")
+        .build();
+    assert_eq!(sql, "DEFINE FUNCTION OVERWRITE fn::one() { RETURN 1 }");
+
+    let sql = QueryBuilder::define_function("fn::one")
+        .if_not_exists()
+        .r#return("1")
+        .build();
+    assert_eq!(sql, "DEFINE FUNCTION IF NOT EXISTS fn::one() { RETURN 1 }");
+}
+
+#[test]
+fn body_composed_from_builders_and_let() {
+    let sql = QueryBuilder::define_function("fn::register")
+        .arg("email", Kind::String)
+        .r#let(
+            "user",
+            QueryBuilder::create("user")
+                .set("email", "$email")
+                .return_after(),
+        )
+        .statement(QueryBuilder::relate("$user.id", "joined", "team:default"))
+        .r#return("$user")
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE FUNCTION fn::register($email: string) { LET $user = (CREATE user SET email = $email RETURN AFTER); RELATE $user.id->joined->team:default; RETURN $user }"
+    );
+}
+
+#[test]
+fn let_with_select_subquery_and_raw_value() {
+    let sql = QueryBuilder::define_function("fn::stats")
+        .r#let("limit", "10")
+        .r#let(
+            "$top",
+            QueryBuilder::select(surrealex::fields!(*))
+                .from("post")
+                .limit(10),
+        )
+        .r#return("$top")
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE FUNCTION fn::stats() { LET $limit = 10; LET $top = (SELECT * FROM post LIMIT 10); RETURN $top }"
+    );
+}
+
+#[test]
+fn return_subquery() {
+    let sql = QueryBuilder::define_function("fn::admins")
+        .r#return(
+            QueryBuilder::select(surrealex::fields!("id"))
+                .from("user")
+                .r#where("admin = true"),
+        )
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE FUNCTION fn::admins() { RETURN (SELECT id FROM user WHERE admin = true) }"
+    );
+}
+
+#[test]
+fn comment_and_permissions() {
+    let sql = QueryBuilder::define_function("fn::secret")
+        .r#return("1")
+        .permissions_where("$auth.admin = true")
+        .comment("Admins only")
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE FUNCTION fn::secret() { RETURN 1 } COMMENT \"Admins only\" PERMISSIONS WHERE $auth.admin = true"
+    );
+}
+
+#[test]
+fn permissions_none_and_full() {
+    let sql = QueryBuilder::define_function("fn::a")
+        .permissions_none()
+        .build();
+    assert_eq!(sql, "DEFINE FUNCTION fn::a() {} PERMISSIONS NONE");
+
+    let sql = QueryBuilder::define_function("fn::a")
+        .permissions_full()
+        .build();
+    assert_eq!(sql, "DEFINE FUNCTION fn::a() {} PERMISSIONS FULL");
+}
+
+#[test]
+fn function_call_renders_arguments() {
+    assert_eq!(FunctionCall::new("fn::now").to_string(), "fn::now()");
+    assert_eq!(
+        FunctionCall::new("fn::greet")
+            .arg("'Tobie'")
+            .arg("$times")
+            .to_string(),
+        "fn::greet('Tobie', $times)"
+    );
+}
+
+#[test]
+fn function_call_as_select_field() {
+    let sql = QueryBuilder::select(surrealex::fields!(
+        (FunctionCall::new("fn::greet").arg("name")),
+        (FunctionCall::new("fn::age").arg("born"), "age")
+    ))
+    .from("person")
+    .build();
+    assert_eq!(
+        sql,
+        "SELECT fn::greet(name), fn::age(born) AS age FROM person"
+    );
+}
+
+#[test]
+fn function_call_as_condition() {
+    let sql = QueryBuilder::delete("session")
+        .r#where(FunctionCall::new("fn::expired").arg("$this"))
+        .build();
+    assert_eq!(sql, "DELETE FROM session WHERE fn::expired($this)");
+
+    let condition = Condition::from(FunctionCall::new("fn::a")).and("b = 1");
+    assert_eq!(condition.to_string(), "(fn::a() AND b = 1)");
+}
+
+#[test]
+fn versioned_builder_renders_define_function() {
+    let sql = QueryBuilder::with_version(SurrealV1)
+        .define_function("fn::greet")
+        .arg("name", Kind::String)
+        .r#return("'Hello, ' + $name")
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE FUNCTION fn::greet($name: string) { RETURN 'Hello, ' + $name }"
+    );
+}
@@ -0,0 +1,228 @@
+use surrealex::types::define::index::{
+    Distance, HnswParams, MtreeParams, SearchParams, VectorType,
+};
+use surrealex::{QueryBuilder, SurrealV1};
+
+#[test]
+fn build_define_index_with_fields() {
+    let sql = QueryBuilder::define_index("idx_name", "user")
+        .fields(vec!["name"])
+        .build();
+    assert_eq!(sql, "DEFINE INDEX idx_name ON TABLE user FIELDS name");
+}
+
+#[test]
+fn build_define_index_with_multiple_columns() {
+    let sql = QueryBuilder::define_index("idx_account", "user")
+        .columns(vec!["account", "email"])
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE INDEX idx_account ON TABLE user COLUMNS account, email"
+    );
+}
+
+#[test]
+fn fields_after_columns_replaces_columns() {
+    let sql = QueryBuilder::define_index("idx", "user")
+        .columns(vec!["a"])
+        .fields(vec!["b"])
+        .build();
+    assert_eq!(sql, "DEFINE INDEX idx ON TABLE user FIELDS b");
+}
+
+#[test]
+fn overwrite_and_if_not_exists() {
+    let sql = QueryBuilder::define_index("idx", "user")
+        .overwrite()
+        .fields(vec!["email"])
+        .build();
+    assert_eq!(sql, "DEFINE INDEX OVERWRITE idx ON TABLE user FIELDS email");
+
+    let sql = QueryBuilder::define_index("idx", "user")
+        .if_not_exists()
+        .fields(vec!["email"])
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE INDEX IF NOT EXISTS idx ON TABLE user FIELDS email"
+    );
+}
+
+#[test]
+fn unique_index() {
+    let sql = QueryBuilder::define_index("userEmailIndex", "user")
+        .fields(vec!["email"])
+        .unique()
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE INDEX userEmailIndex ON TABLE user FIELDS email UNIQUE"
+    );
+}
+
+#[test]
+fn search_index_without_ranking() {
+    let sql = QueryBuilder::define_index("ft", "book")
+        .fields(vec!["title"])
+        .search(SearchParams::new("simple"))
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE INDEX ft ON TABLE book FIELDS title SEARCH ANALYZER simple"
+    );
+}
+
+#[test]
+fn search_index_with_default_bm25() {
+    let sql = QueryBuilder::define_index("ft", "book")
+        .fields(vec!["title"])
+        .search(SearchParams::new("simple").bm25())
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE INDEX ft ON TABLE book FIELDS title SEARCH ANALYZER simple BM25"
+    );
+}
+
+#[test]
+fn search_index_with_bm25_params_and_highlights() {
+    let sql = QueryBuilder::define_index("ft", "book")
+        .fields(vec!["content"])
+        .search(
+            SearchParams::new("ascii")
+                .bm25_params(1.2, 0.75)
+                .highlights(),
+        )
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE INDEX ft ON TABLE book FIELDS content SEARCH ANALYZER ascii BM25(1.2,0.75) HIGHLIGHTS"
+    );
+}
+
+#[test]
+fn mtree_index() {
+    let sql = QueryBuilder::define_index("idx_mtree", "vec")
+        .fields(vec!["embedding"])
+        .mtree(MtreeParams::new(4))
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE INDEX idx_mtree ON TABLE vec FIELDS embedding MTREE DIMENSION 4"
+    );
+}
+
+#[test]
+fn mtree_index_with_all_options() {
+    let sql = QueryBuilder::define_index("idx_mtree", "vec")
+        .fields(vec!["embedding"])
+        .mtree(
+            MtreeParams::new(3)
+                .vector_type(VectorType::F32)
+                .dist(Distance::Euclidean)
+                .capacity(40),
+        )
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE INDEX idx_mtree ON TABLE vec FIELDS embedding MTREE DIMENSION 3 TYPE F32 DIST EUCLIDEAN CAPACITY 40"
+    );
+}
+
+#[test]
+fn hnsw_index() {
+    let sql = QueryBuilder::define_index("idx_hnsw", "vec")
+        .fields(vec!["embedding"])
+        .hnsw(HnswParams::new(128))
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE INDEX idx_hnsw ON TABLE vec FIELDS embedding HNSW DIMENSION 128"
+    );
+}
+
+#[test]
+fn hnsw_index_with_all_options() {
+    let sql = QueryBuilder::define_index("idx_hnsw", "vec")
+        .fields(vec!["embedding"])
+        .hnsw(
+            HnswParams::new(4)
+                .vector_type(VectorType::I16)
+                .dist(Distance::Manhattan)
+                .efc(150)
+                .m(12),
+        )
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE INDEX idx_hnsw ON TABLE vec FIELDS embedding HNSW DIMENSION 4 TYPE I16 DIST MANHATTAN EFC 150 M 12"
+    );
+}
+
+#[test]
+fn distance_variants_render() {
+    let cases = [
+        (Distance::Chebyshev, "CHEBYSHEV"),
+        (Distance::Cosine, "COSINE"),
+        (Distance::Euclidean, "EUCLIDEAN"),
+        (Distance::Hamming, "HAMMING"),
+        (Distance::Jaccard, "JACCARD"),
+        (Distance::Manhattan, "MANHATTAN"),
+        (Distance::Minkowski(3), "MINKOWSKI 3"),
+        (Distance::Pearson, "PEARSON"),
+    ];
+
+    for (dist, expected) in cases {
+        assert_eq!(dist.to_string(), expected);
+    }
+}
+
+#[test]
+fn vector_type_variants_render() {
+    let cases = [
+        (VectorType::F64, "F64"),
+        (VectorType::F32, "F32"),
+        (VectorType::I64, "I64"),
+        (VectorType::I32, "I32"),
+        (VectorType::I16, "I16"),
+    ];
+
+    for (vector_type, expected) in cases {
+        assert_eq!(vector_type.to_string(), expected);
+    }
+}
+
+#[test]
+fn calling_index_kind_multiple_times_uses_last_value() {
+    let sql = QueryBuilder::define_index("idx", "user")
+        .fields(vec!["email"])
+        .search(SearchParams::new("ascii"))
+        .unique()
+        .build();
+    assert_eq!(sql, "DEFINE INDEX idx ON TABLE user FIELDS email UNIQUE");
+}
+
+#[test]
+fn comment_and_concurrently() {
+    let sql = QueryBuilder::define_index("idx", "user")
+        .concurrently()
+        .comment("Email lookup")
+        .fields(vec!["email"])
+        .unique()
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE INDEX idx ON TABLE user FIELDS email UNIQUE COMMENT \"Email lookup\" CONCURRENTLY"
+    );
+}
+
+#[test]
+fn versioned_builder_renders_define_index() {
+    let sql = QueryBuilder::with_version(SurrealV1)
+        .define_index("idx", "user")
+        .fields(vec!["email"])
+        .unique()
+        .build();
+    assert_eq!(sql, "DEFINE INDEX idx ON TABLE user FIELDS email UNIQUE");
+}
@@ -0,0 +1,37 @@
+use surrealex::{QueryBuilder, SurrealV1};
+
+#[test]
+fn build_define_namespace() {
+    let sql = QueryBuilder::define_namespace("acme").build();
+    assert_eq!(sql, "DEFINE NAMESPACE acme");
+}
+
+#[test]
+fn namespace_with_mode_and_comment() {
+    let sql = QueryBuilder::define_namespace("acme")
+        .if_not_exists()
+        .comment("tenant \"acme\"")
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE NAMESPACE IF NOT EXISTS acme COMMENT \"tenant \\\"acme\\\"\""
+    );
+}
+
+#[test]
+fn overwrite_replaces_if_not_exists() {
+    let sql = QueryBuilder::define_namespace("acme")
+        .if_not_exists()
+        .overwrite()
+        .build();
+    assert_eq!(sql, "DEFINE NAMESPACE OVERWRITE acme");
+}
+
+#[test]
+fn versioned_builder_renders_define_namespace() {
+    let sql = QueryBuilder::with_version(SurrealV1)
+        .define_namespace("acme")
+        .comment("tenant")
+        .build();
+    assert_eq!(sql, "DEFINE NAMESPACE acme COMMENT \"tenant\"");
+}
@@ -0,0 +1,76 @@
+use surrealex::enums::{Condition, SelectionFields};
+use surrealex::{QueryBuilder, SurrealV1};
+
+#[test]
+fn build_define_param() {
+    let sql = QueryBuilder::define_param("endpoint", "\"https://api.example.com\"").build();
+    assert_eq!(
+        sql,
+        "DEFINE PARAM $endpoint VALUE \"https://api.example.com\""
+    );
+}
+
+#[test]
+fn leading_dollar_is_optional() {
+    let sql = QueryBuilder::define_param("$limit", "100").build();
+    assert_eq!(sql, "DEFINE PARAM $limit VALUE 100");
+}
+
+#[test]
+fn array_value() {
+    let sql = QueryBuilder::define_param("roles", vec!["'admin'", "'editor'"]).build();
+    assert_eq!(sql, "DEFINE PARAM $roles VALUE ['admin', 'editor']");
+}
+
+#[test]
+fn subquery_value() {
+    let sql = QueryBuilder::define_param(
+        "admins",
+        QueryBuilder::select(SelectionFields::All)
+            .from("user")
+            .r#where("role = 'admin'"),
+    )
+    .build();
+    assert_eq!(
+        sql,
+        "DEFINE PARAM $admins VALUE (SELECT * FROM user WHERE role = 'admin')"
+    );
+}
+
+#[test]
+fn permissions_variants() {
+    let sql = QueryBuilder::define_param("a", "1")
+        .permissions_full()
+        .build();
+    assert_eq!(sql, "DEFINE PARAM $a VALUE 1 PERMISSIONS FULL");
+
+    let sql = QueryBuilder::define_param("a", "1")
+        .permissions_where(Condition::new("$auth.admin = true"))
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE PARAM $a VALUE 1 PERMISSIONS WHERE $auth.admin = true"
+    );
+}
+
+#[test]
+fn mode_comment_and_permissions() {
+    let sql = QueryBuilder::define_param("limit", "50")
+        .overwrite()
+        .comment("page size")
+        .permissions_none()
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE PARAM OVERWRITE $limit VALUE 50 COMMENT \"page size\" PERMISSIONS NONE"
+    );
+}
+
+#[test]
+fn versioned_builder_renders_define_param() {
+    let sql = QueryBuilder::with_version(SurrealV1)
+        .define_param("limit", "50")
+        .permissions_full()
+        .build();
+    assert_eq!(sql, "DEFINE PARAM $limit VALUE 50 PERMISSIONS FULL");
+}
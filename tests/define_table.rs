@@ -0,0 +1,241 @@
+use std::time::Duration;
+use surrealex::enums::Condition;
+use surrealex::types::define::{Permission, PermissionKind};
+use surrealex::versioning::SurrealV3;
+use surrealex::{QueryBuilder, SurrealV1, SurrealV2};
+
+#[test]
+fn build_define_table() {
+    let sql = QueryBuilder::define_table("person").build();
+    assert_eq!(sql, "DEFINE TABLE person");
+}
+
+#[test]
+fn overwrite_modifier() {
+    let sql = QueryBuilder::define_table("person").overwrite().build();
+    assert_eq!(sql, "DEFINE TABLE OVERWRITE person");
+}
+
+#[test]
+fn if_not_exists_modifier() {
+    let sql = QueryBuilder::define_table("person").if_not_exists().build();
+    assert_eq!(sql, "DEFINE TABLE IF NOT EXISTS person");
+}
+
+#[test]
+fn calling_overwrite_then_if_not_exists_uses_last_value() {
+    let sql = QueryBuilder::define_table("person")
+        .overwrite()
+        .if_not_exists()
+        .build();
+    assert_eq!(sql, "DEFINE TABLE IF NOT EXISTS person");
+}
+
+#[test]
+fn drop_table() {
+    let sql = QueryBuilder::define_table("reading").drop().build();
+    assert_eq!(sql, "DEFINE TABLE reading DROP");
+}
+
+#[test]
+fn schemafull_table() {
+    let sql = QueryBuilder::define_table("person").schemafull().build();
+    assert_eq!(sql, "DEFINE TABLE person SCHEMAFULL");
+}
+
+#[test]
+fn schemaless_table() {
+    let sql = QueryBuilder::define_table("person").schemaless().build();
+    assert_eq!(sql, "DEFINE TABLE person SCHEMALESS");
+}
+
+#[test]
+fn type_any_and_normal() {
+    let sql = QueryBuilder::define_table("person").type_any().build();
+    assert_eq!(sql, "DEFINE TABLE person TYPE ANY");
+
+    let sql = QueryBuilder::define_table("person").type_normal().build();
+    assert_eq!(sql, "DEFINE TABLE person TYPE NORMAL");
+}
+
+#[test]
+fn type_relation() {
+    let sql = QueryBuilder::define_table("likes")
+        .type_relation(vec!["user"], vec!["post"])
+        .build();
+    assert_eq!(sql, "DEFINE TABLE likes TYPE RELATION IN user OUT post");
+}
+
+#[test]
+fn type_relation_with_multiple_tables_and_enforced() {
+    let sql = QueryBuilder::define_table("likes")
+        .type_relation(vec!["user", "org"], vec!["post", "comment"])
+        .enforced()
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE TABLE likes TYPE RELATION IN user | org OUT post | comment ENFORCED"
+    );
+}
+
+#[test]
+fn type_relation_without_constraints() {
+    let sql = QueryBuilder::define_table("likes")
+        .type_relation(Vec::<&str>::new(), Vec::new())
+        .build();
+    assert_eq!(sql, "DEFINE TABLE likes TYPE RELATION");
+}
+
+#[test]
+fn enforced_without_relation_has_no_effect() {
+    let sql = QueryBuilder::define_table("person")
+        .type_normal()
+        .enforced()
+        .build();
+    assert_eq!(sql, "DEFINE TABLE person TYPE NORMAL");
+}
+
+#[test]
+fn view_from_select() {
+    let sql = QueryBuilder::define_table("avg_product")
+        .as_select(
+            QueryBuilder::select(surrealex::fields!(("math::mean(price)", "price")))
+                .from("product"),
+        )
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE TABLE avg_product AS SELECT math::mean(price) AS price FROM product"
+    );
+}
+
+#[test]
+fn changefeed_with_raw_duration() {
+    let sql = QueryBuilder::define_table("reading")
+        .changefeed("3d")
+        .build();
+    assert_eq!(sql, "DEFINE TABLE reading CHANGEFEED 3d");
+}
+
+#[test]
+fn changefeed_with_std_duration_and_include_original() {
+    let sql = QueryBuilder::define_table("reading")
+        .changefeed(Duration::from_secs(2 * 86_400))
+        .include_original()
+        .build();
+    assert_eq!(sql, "DEFINE TABLE reading CHANGEFEED 2d INCLUDE ORIGINAL");
+}
+
+#[test]
+fn include_original_without_changefeed_has_no_effect() {
+    let sql = QueryBuilder::define_table("reading")
+        .include_original()
+        .build();
+    assert_eq!(sql, "DEFINE TABLE reading");
+}
+
+#[test]
+fn permissions_none_and_full() {
+    let sql = QueryBuilder::define_table("secret")
+        .permissions_none()
+        .build();
+    assert_eq!(sql, "DEFINE TABLE secret PERMISSIONS NONE");
+
+    let sql = QueryBuilder::define_table("public")
+        .permissions_full()
+        .build();
+    assert_eq!(sql, "DEFINE TABLE public PERMISSIONS FULL");
+}
+
+#[test]
+fn permissions_for_multiple_rules() {
+    let sql = QueryBuilder::define_table("post")
+        .permissions_for(
+            vec![PermissionKind::Select],
+            Condition::new("published = true").or("user = $auth.id"),
+        )
+        .permissions_for(
+            vec![PermissionKind::Create, PermissionKind::Update],
+            "user = $auth.id",
+        )
+        .permissions_for(vec![PermissionKind::Delete], Permission::None)
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE TABLE post PERMISSIONS FOR select WHERE (published = true OR user = $auth.id) FOR create, update WHERE user = $auth.id FOR delete NONE"
+    );
+}
+
+#[test]
+fn permissions_for_replaces_none() {
+    let sql = QueryBuilder::define_table("post")
+        .permissions_none()
+        .permissions_for(vec![PermissionKind::Select], Permission::Full)
+        .build();
+    assert_eq!(sql, "DEFINE TABLE post PERMISSIONS FOR select FULL");
+}
+
+#[test]
+fn comment_is_quoted_and_escaped() {
+    let sql = QueryBuilder::define_table("person")
+        .comment("People \"and\" things")
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE TABLE person COMMENT \"People \\\"and\\\" things\""
+    );
+}
+
+#[test]
+fn all_clauses_combined() {
+    let sql = QueryBuilder::define_table("post")
+        .overwrite()
+        .schemafull()
+        .type_normal()
+        .changefeed("1d")
+        .permissions_full()
+        .comment("Blog posts")
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE TABLE OVERWRITE post SCHEMAFULL TYPE NORMAL CHANGEFEED 1d PERMISSIONS FULL COMMENT \"Blog posts\""
+    );
+}
+
+#[test]
+fn clauses_are_emitted_in_correct_order_regardless_of_call_order() {
+    let sql = QueryBuilder::define_table("reading")
+        .comment("Sensor data")
+        .permissions_none()
+        .changefeed("7d")
+        .type_any()
+        .schemaless()
+        .drop()
+        .if_not_exists()
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE TABLE IF NOT EXISTS reading DROP SCHEMALESS TYPE ANY CHANGEFEED 7d PERMISSIONS NONE COMMENT \"Sensor data\""
+    );
+}
+
+#[test]
+fn versioned_builders_render_define_table() {
+    let sql = QueryBuilder::with_version(SurrealV1)
+        .define_table("person")
+        .schemafull()
+        .build();
+    assert_eq!(sql, "DEFINE TABLE person SCHEMAFULL");
+
+    let sql = QueryBuilder::with_version(SurrealV2)
+        .define_table("person")
+        .overwrite()
+        .build();
+    assert_eq!(sql, "DEFINE TABLE OVERWRITE person");
+
+    let sql = QueryBuilder::with_version(SurrealV3)
+        .define_table("person")
+        .if_not_exists()
+        .build();
+    assert_eq!(sql, "DEFINE TABLE IF NOT EXISTS person");
+}
@@ -0,0 +1,108 @@
+use surrealex::traits::IntoStatement;
+use surrealex::types::control::{Break, Continue, Throw};
+use surrealex::types::script::Param;
+use surrealex::{QueryBuilder, SurrealV1, fields};
+
+#[test]
+fn loop_over_subquery() {
+    let for_loop = QueryBuilder::r#for(
+        "session",
+        QueryBuilder::select(fields!("id"))
+            .from("session")
+            .r#where("expires < time::now()"),
+    );
+    let session = for_loop.param();
+    let sql = for_loop
+        .statement(QueryBuilder::delete(session.field("id")))
+        .build();
+    assert_eq!(
+        sql,
+        "FOR $session IN (SELECT id FROM session WHERE expires < time::now()) { DELETE FROM $session.id }"
+    );
+}
+
+#[test]
+fn loop_over_array_literal() {
+    let sql = QueryBuilder::r#for("name", vec!["'Tobie'", "'Jaime'"])
+        .statement(QueryBuilder::create("person").set("name", "$name"))
+        .build();
+    assert_eq!(
+        sql,
+        "FOR $name IN ['Tobie', 'Jaime'] { CREATE person SET name = $name }"
+    );
+}
+
+#[test]
+fn loop_over_param() {
+    let mut script = QueryBuilder::script();
+    let ids = script.r#let("ids", vec!["user:1", "user:2"]);
+    let sql = script
+        .statement(
+            QueryBuilder::r#for("$id", &ids)
+                .statement(QueryBuilder::update(Param::new("id")).set("seen", "true")),
+        )
+        .build();
+    assert_eq!(
+        sql,
+        "LET $ids = [user:1, user:2]; FOR $id IN $ids { UPDATE $id SET seen = true }"
+    );
+}
+
+#[test]
+fn empty_body_renders_empty_block() {
+    let sql = QueryBuilder::r#for("x", "$items").build();
+    assert_eq!(sql, "FOR $x IN $items {}");
+}
+
+#[test]
+fn break_continue_and_throw() {
+    let for_loop = QueryBuilder::r#for("n", "[1, 2, 3, 4]");
+    let n = for_loop.param();
+    let sql = for_loop
+        .statement(QueryBuilder::r#if(format!("{n} = 2"), Continue))
+        .statement(QueryBuilder::r#if(format!("{n} = 4"), Break))
+        .statement(QueryBuilder::r#if(
+            format!("{n} < 0"),
+            Throw::new("negative"),
+        ))
+        .statement(QueryBuilder::create("number").set("value", &n))
+        .build();
+    assert_eq!(
+        sql,
+        "FOR $n IN [1, 2, 3, 4] { IF $n = 2 { CONTINUE }; IF $n = 4 { BREAK }; IF $n < 0 { THROW \"negative\" }; CREATE number SET value = $n }"
+    );
+}
+
+#[test]
+fn throw_statements() {
+    assert_eq!(
+        Throw::new("Say \"no\"").into_statement(),
+        "THROW \"Say \\\"no\\\"\""
+    );
+    assert_eq!(Throw::expr("$error").into_statement(), "THROW $error");
+    assert_eq!(Break.into_statement(), "BREAK");
+    assert_eq!(Continue.into_statement(), "CONTINUE");
+}
+
+#[test]
+fn nested_loops() {
+    let sql = QueryBuilder::r#for("a", "[1, 2]")
+        .statement(
+            QueryBuilder::r#for("b", "[3, 4]")
+                .statement(QueryBuilder::create("pair").set("a", "$a").set("b", "$b")),
+        )
+        .build();
+    assert_eq!(
+        sql,
+        "FOR $a IN [1, 2] { FOR $b IN [3, 4] { CREATE pair SET a = $a, b = $b } }"
+    );
+}
+
+#[test]
+fn versioned_builder_renders_for_loop() {
+    let sql = QueryBuilder::with_version(SurrealV1)
+        .r#for("x", "$items")
+        .statement(Break)
+        .build();
+    assert_eq!(sql, "FOR $x IN $items { BREAK }");
+}
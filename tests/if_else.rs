@@ -0,0 +1,110 @@
+use surrealex::enums::Condition;
+use surrealex::types::define::event::EventType;
+use surrealex::{QueryBuilder, SurrealV1, SurrealV2};
+
+#[test]
+fn single_branch() {
+    let sql = QueryBuilder::r#if("$value > 10", QueryBuilder::create("alert")).build();
+    assert_eq!(sql, "IF $value > 10 { CREATE alert }");
+}
+
+#[test]
+fn else_if_and_else_branches() {
+    let sql = QueryBuilder::r#if(
+        "$auth.role = 'admin'",
+        QueryBuilder::select(surrealex::fields!("*")).from("account"),
+    )
+    .else_if(
+        Condition::new("$auth.role = 'user'").and("$auth.active = true"),
+        QueryBuilder::select(surrealex::fields!("id", "name")).from("account"),
+    )
+    .r#else("[]")
+    .build();
+    assert_eq!(
+        sql,
+        "IF $auth.role = 'admin' { SELECT * FROM account } ELSE IF ($auth.role = 'user' AND $auth.active = true) { SELECT id, name FROM account } ELSE { [] }"
+    );
+}
+
+#[test]
+fn script_body() {
+    let mut body = QueryBuilder::script();
+    let user = body.r#let("user", "$after.author");
+    let body = body.statement(QueryBuilder::update(&user).set("posts", "posts + 1"));
+
+    let sql = QueryBuilder::r#if(EventType::Create, body).build();
+    assert_eq!(
+        sql,
+        "IF $event = \"CREATE\" { LET $user = $after.author; UPDATE $user SET posts = posts + 1 }"
+    );
+}
+
+#[test]
+fn else_replaces_previous_else() {
+    let sql = QueryBuilder::r#if("true", "1")
+        .r#else("2")
+        .r#else("3")
+        .build();
+    assert_eq!(sql, "IF true { 1 } ELSE { 3 }");
+}
+
+#[test]
+fn nested_if_in_event_body() {
+    let sql = QueryBuilder::define_event("publish", "post")
+        .then(QueryBuilder::r#if(
+            "$after.published = true",
+            QueryBuilder::create("notification").set("post", "$after.id"),
+        ))
+        .build();
+    assert_eq!(
+        sql,
+        "DEFINE EVENT publish ON TABLE post THEN { IF $after.published = true { CREATE notification SET post = $after.id } }"
+    );
+}
+
+#[test]
+fn v1_renders_then_end_syntax() {
+    let sql = QueryBuilder::with_version(SurrealV1)
+        .r#if("$value > 10", QueryBuilder::create("alert"))
+        .else_if("$value > 5", QueryBuilder::create("warning"))
+        .r#else("NONE")
+        .build();
+    assert_eq!(
+        sql,
+        "IF $value > 10 THEN (CREATE alert) ELSE IF $value > 5 THEN (CREATE warning) ELSE (NONE) END"
+    );
+}
+
+#[test]
+fn v1_single_branch_is_terminated() {
+    let sql = QueryBuilder::with_version(SurrealV1)
+        .r#if("$value > 10", QueryBuilder::create("alert"))
+        .build();
+    assert_eq!(sql, "IF $value > 10 THEN (CREATE alert) END");
+}
+
+#[test]
+fn v1_script_body_renders_block() {
+    let body = QueryBuilder::script()
+        .statement(QueryBuilder::update("a").set("x", "1"))
+        .statement(QueryBuilder::update("b").set("y", "2"));
+    let fallback = QueryBuilder::script().statement(QueryBuilder::create("log"));
+
+    let sql = QueryBuilder::with_version(SurrealV1)
+        .r#if("$c", body)
+        .r#else(fallback)
+        .build();
+    assert_eq!(
+        sql,
+        "IF $c THEN { UPDATE a SET x = 1; UPDATE b SET y = 2 } ELSE (CREATE log) END"
+    );
+}
+
+#[test]
+fn versioned_builder_renders_if() {
+    let sql = QueryBuilder::with_version(SurrealV2)
+        .r#if("$value > 10", QueryBuilder::create("alert"))
+        .r#else("NONE")
+        .build();
+    assert_eq!(sql, "IF $value > 10 { CREATE alert } ELSE { NONE }");
+}
@@ -0,0 +1,51 @@
+use surrealex::enums::Base;
+use surrealex::{QueryBuilder, SurrealV1};
+
+#[test]
+fn info_for_root_namespace_and_database() {
+    assert_eq!(QueryBuilder::info().root().build(), "INFO FOR ROOT");
+    assert_eq!(QueryBuilder::info().namespace().build(), "INFO FOR NS");
+    assert_eq!(QueryBuilder::info().database().build(), "INFO FOR DB");
+}
+
+#[test]
+fn info_for_table() {
+    let sql = QueryBuilder::info().table("user").build();
+    assert_eq!(sql, "INFO FOR TABLE user");
+}
+
+#[test]
+fn info_for_user() {
+    assert_eq!(
+        QueryBuilder::info().user("admin").build(),
+        "INFO FOR USER admin"
+    );
+    assert_eq!(
+        QueryBuilder::info().user_on("admin", Base::Root).build(),
+        "INFO FOR USER admin ON ROOT"
+    );
+}
+
+#[test]
+fn structure_modifier() {
+    assert_eq!(
+        QueryBuilder::info().database().structure().build(),
+        "INFO FOR DB STRUCTURE"
+    );
+    assert_eq!(
+        QueryBuilder::info()
+            .user_on("admin", Base::Namespace)
+            .structure()
+            .build(),
+        "INFO FOR USER admin ON NAMESPACE STRUCTURE"
+    );
+}
+
+#[test]
+fn versioned_builder_renders_info() {
+    let sql = QueryBuilder::with_version(SurrealV1)
+        .info()
+        .table("user")
+        .build();
+    assert_eq!(sql, "INFO FOR TABLE user");
+}
@@ -0,0 +1,78 @@
+use surrealex::enums::{Condition, SelectionFields};
+use surrealex::traits::IntoStatement;
+use surrealex::{QueryBuilder, SurrealV1, fields};
+
+#[test]
+fn live_select_all() {
+    let sql = QueryBuilder::live(SelectionFields::All)
+        .from("order")
+        .build();
+    assert_eq!(sql, "LIVE SELECT * FROM order");
+}
+
+#[test]
+fn live_select_fields_with_alias() {
+    let sql = QueryBuilder::live(fields!("id", ("total", "amount")))
+        .from("order")
+        .build();
+    assert_eq!(sql, "LIVE SELECT id, total AS amount FROM order");
+}
+
+#[test]
+fn live_select_with_where_and_fetch() {
+    let sql = QueryBuilder::live(SelectionFields::All)
+        .from("order")
+        .r#where(Condition::new("status = 'open'").and("total > 100"))
+        .r#where("region = $region")
+        .fetch(vec!["customer", "items"])
+        .build();
+    assert_eq!(
+        sql,
+        "LIVE SELECT * FROM order WHERE (status = 'open' AND total > 100) AND region = $region FETCH customer, items"
+    );
+}
+
+#[test]
+fn live_select_diff() {
+    let sql = QueryBuilder::live_diff()
+        .from("order")
+        .r#where("status = 'open'")
+        .build();
+    assert_eq!(sql, "LIVE SELECT DIFF FROM order WHERE status = 'open'");
+}
+
+#[test]
+fn kill_uuid() {
+    let sql = QueryBuilder::kill("0189d6e3-8eac-703a-9a48-d9faa78b44b9").build();
+    assert_eq!(sql, "KILL \"0189d6e3-8eac-703a-9a48-d9faa78b44b9\"");
+}
+
+#[test]
+fn kill_param() {
+    let sql = QueryBuilder::kill("$live_id").build();
+    assert_eq!(sql, "KILL $live_id");
+}
+
+#[test]
+fn live_and_kill_compose_into_scripts() {
+    let script = [
+        QueryBuilder::live(SelectionFields::All)
+            .from("order")
+            .into_statement(),
+        QueryBuilder::kill("$live_id").into_statement(),
+    ]
+    .join("; ");
+    assert_eq!(script, "LIVE SELECT * FROM order; KILL $live_id");
+}
+
+#[test]
+fn versioned_builder_renders_live_and_kill() {
+    let sql = QueryBuilder::with_version(SurrealV1)
+        .live(SelectionFields::All)
+        .from("order")
+        .build();
+    assert_eq!(sql, "LIVE SELECT * FROM order");
+
+    let sql = QueryBuilder::with_version(SurrealV1).kill("$id").build();
+    assert_eq!(sql, "KILL $id");
+}
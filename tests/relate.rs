@@ -0,0 +1,183 @@
+use std::time::Duration;
+use surrealex::{QueryBuilder, SurrealV1};
+
+#[test]
+fn build_relate_between_record_ids() {
+    let sql = QueryBuilder::relate("person:tobie", "wrote", "article:surreal").build();
+    assert_eq!(sql, "RELATE person:tobie->wrote->article:surreal");
+}
+
+#[test]
+fn only_emits_relate_only() {
+    let sql = QueryBuilder::relate("person:tobie", "wrote", "article:surreal")
+        .only()
+        .build();
+    assert_eq!(sql, "RELATE ONLY person:tobie->wrote->article:surreal");
+}
+
+#[test]
+fn relate_accepts_owned_strings() {
+    let from = String::from("person:tobie");
+    let to = String::from("person:jaime");
+    let sql = QueryBuilder::relate(from, "knows", to).build();
+    assert_eq!(sql, "RELATE person:tobie->knows->person:jaime");
+}
+
+#[test]
+fn relate_accepts_parameters() {
+    let sql = QueryBuilder::relate("$person", "wrote", "$article").build();
+    assert_eq!(sql, "RELATE $person->wrote->$article");
+}
+
+#[test]
+fn relate_accepts_arrays_of_ids() {
+    let sql = QueryBuilder::relate(
+        vec!["person:tobie", "person:jaime"],
+        "likes",
+        vec!["article:one", "article:two"],
+    )
+    .build();
+    assert_eq!(
+        sql,
+        "RELATE [person:tobie, person:jaime]->likes->[article:one, article:two]"
+    );
+}
+
+#[test]
+fn relate_accepts_subqueries_on_both_sides() {
+    let sql = QueryBuilder::relate(
+        QueryBuilder::select(surrealex::fields!("id"))
+            .from("person")
+            .r#where("admin = true"),
+        "moderates",
+        QueryBuilder::select(surrealex::fields!("id")).from("forum"),
+    )
+    .build();
+    assert_eq!(
+        sql,
+        "RELATE (SELECT id FROM person WHERE admin = true)->moderates->(SELECT id FROM forum)"
+    );
+}
+
+#[test]
+fn content_clause() {
+    let sql = QueryBuilder::relate("person:tobie", "wrote", "article:surreal")
+        .content("{ source: 'Apple notes', tags: ['notes', 'markdown'] }")
+        .build();
+    assert_eq!(
+        sql,
+        "RELATE person:tobie->wrote->article:surreal CONTENT { source: 'Apple notes', tags: ['notes', 'markdown'] }"
+    );
+}
+
+#[test]
+fn set_multiple_fields() {
+    let sql = QueryBuilder::relate("person:tobie", "wrote", "article:surreal")
+        .set("time.written", "time::now()")
+        .set("source", "'Apple notes'")
+        .build();
+    assert_eq!(
+        sql,
+        "RELATE person:tobie->wrote->article:surreal SET time.written = time::now(), source = 'Apple notes'"
+    );
+}
+
+#[test]
+fn set_after_content_replaces_content() {
+    let sql = QueryBuilder::relate("a:1", "e", "b:1")
+        .content("{ x: 1 }")
+        .set("y", "2")
+        .build();
+    assert_eq!(sql, "RELATE a:1->e->b:1 SET y = 2");
+}
+
+#[test]
+fn content_after_set_replaces_set() {
+    let sql = QueryBuilder::relate("a:1", "e", "b:1")
+        .set("y", "2")
+        .content("{ x: 1 }")
+        .build();
+    assert_eq!(sql, "RELATE a:1->e->b:1 CONTENT { x: 1 }");
+}
+
+#[test]
+fn return_variants() {
+    let sql = QueryBuilder::relate("a:1", "e", "b:1")
+        .return_none()
+        .build();
+    assert_eq!(sql, "RELATE a:1->e->b:1 RETURN NONE");
+
+    let sql = QueryBuilder::relate("a:1", "e", "b:1")
+        .return_before()
+        .build();
+    assert_eq!(sql, "RELATE a:1->e->b:1 RETURN BEFORE");
+
+    let sql = QueryBuilder::relate("a:1", "e", "b:1")
+        .return_after()
+        .build();
+    assert_eq!(sql, "RELATE a:1->e->b:1 RETURN AFTER");
+
+    let sql = QueryBuilder::relate("a:1", "e", "b:1")
+        .return_diff()
+        .build();
+    assert_eq!(sql, "RELATE a:1->e->b:1 RETURN DIFF");
+}
+
+#[test]
+fn return_params_and_value() {
+    let sql = QueryBuilder::relate("a:1", "e", "b:1")
+        .return_params(vec!["in", "out"])
+        .build();
+    assert_eq!(sql, "RELATE a:1->e->b:1 RETURN in, out");
+
+    let sql = QueryBuilder::relate("a:1", "e", "b:1")
+        .return_value("id")
+        .build();
+    assert_eq!(sql, "RELATE a:1->e->b:1 RETURN VALUE id");
+}
+
+#[test]
+fn timeout_with_std_duration() {
+    let sql = QueryBuilder::relate("a:1", "e", "b:1")
+        .timeout(Duration::from_secs(3))
+        .build();
+    assert_eq!(sql, "RELATE a:1->e->b:1 TIMEOUT 3s");
+}
+
+#[test]
+fn all_clauses_combined() {
+    let sql = QueryBuilder::relate("person:tobie", "wrote", "article:surreal")
+        .only()
+        .set("time.written", "time::now()")
+        .return_after()
+        .timeout("2s")
+        .parallel()
+        .build();
+    assert_eq!(
+        sql,
+        "RELATE ONLY person:tobie->wrote->article:surreal SET time.written = time::now() RETURN AFTER TIMEOUT 2s PARALLEL"
+    );
+}
+
+#[test]
+fn clauses_are_emitted_in_correct_order_regardless_of_call_order() {
+    let sql = QueryBuilder::relate("a:1", "e", "b:1")
+        .parallel()
+        .timeout("1s")
+        .return_none()
+        .content("{ x: 1 }")
+        .only()
+        .build();
+    assert_eq!(
+        sql,
+        "RELATE ONLY a:1->e->b:1 CONTENT { x: 1 } RETURN NONE TIMEOUT 1s PARALLEL"
+    );
+}
+
+#[test]
+fn versioned_builder_renders_relate() {
+    let sql = QueryBuilder::with_version(SurrealV1)
+        .relate("person:tobie", "wrote", "article:surreal")
+        .build();
+    assert_eq!(sql, "RELATE person:tobie->wrote->article:surreal");
+}
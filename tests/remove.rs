@@ -0,0 +1,127 @@
+use surrealex::enums::Base;
+use surrealex::traits::IntoStatement;
+use surrealex::types::define::access::TokenBase;
+use surrealex::{QueryBuilder, SurrealV1};
+
+#[test]
+fn remove_namespace_and_database() {
+    assert_eq!(
+        QueryBuilder::remove().namespace("acme").build(),
+        "REMOVE NAMESPACE acme"
+    );
+    assert_eq!(
+        QueryBuilder::remove().database("app").build(),
+        "REMOVE DATABASE app"
+    );
+}
+
+#[test]
+fn remove_table() {
+    let sql = QueryBuilder::remove().table("user").build();
+    assert_eq!(sql, "REMOVE TABLE user");
+}
+
+#[test]
+fn remove_table_if_exists() {
+    let sql = QueryBuilder::remove().table("user").if_exists().build();
+    assert_eq!(sql, "REMOVE TABLE IF EXISTS user");
+}
+
+#[test]
+fn remove_field_index_and_event_on_table() {
+    assert_eq!(
+        QueryBuilder::remove().field("email", "user").build(),
+        "REMOVE FIELD email ON TABLE user"
+    );
+    assert_eq!(
+        QueryBuilder::remove()
+            .index("unique_email", "user")
+            .if_exists()
+            .build(),
+        "REMOVE INDEX IF EXISTS unique_email ON TABLE user"
+    );
+    assert_eq!(
+        QueryBuilder::remove()
+            .event("email_changed", "user")
+            .build(),
+        "REMOVE EVENT email_changed ON TABLE user"
+    );
+}
+
+#[test]
+fn remove_function() {
+    let sql = QueryBuilder::remove().function("fn::greet").build();
+    assert_eq!(sql, "REMOVE FUNCTION fn::greet");
+}
+
+#[test]
+fn remove_param_adds_dollar_prefix() {
+    assert_eq!(
+        QueryBuilder::remove().param("endpoint").build(),
+        "REMOVE PARAM $endpoint"
+    );
+    assert_eq!(
+        QueryBuilder::remove().param("$endpoint").build(),
+        "REMOVE PARAM $endpoint"
+    );
+}
+
+#[test]
+fn remove_analyzer() {
+    let sql = QueryBuilder::remove().analyzer("ascii").if_exists().build();
+    assert_eq!(sql, "REMOVE ANALYZER IF EXISTS ascii");
+}
+
+#[test]
+fn remove_access_and_user_on_base() {
+    assert_eq!(
+        QueryBuilder::remove()
+            .access("account", Base::Database)
+            .build(),
+        "REMOVE ACCESS account ON DATABASE"
+    );
+    assert_eq!(
+        QueryBuilder::remove()
+            .user("admin", Base::Root)
+            .if_exists()
+            .build(),
+        "REMOVE USER IF EXISTS admin ON ROOT"
+    );
+}
+
+#[test]
+fn remove_statements_compose_into_down_scripts() {
+    let down = [
+        QueryBuilder::remove()
+            .index("unique_email", "user")
+            .into_statement(),
+        QueryBuilder::remove()
+            .field("email", "user")
+            .into_statement(),
+        QueryBuilder::remove().table("user").into_statement(),
+    ]
+    .join("; ");
+    assert_eq!(
+        down,
+        "REMOVE INDEX unique_email ON TABLE user; REMOVE FIELD email ON TABLE user; REMOVE TABLE user"
+    );
+}
+
+#[test]
+fn versioned_builder_renders_remove() {
+    let sql = QueryBuilder::with_version(SurrealV1)
+        .remove()
+        .user("admin", Base::Namespace)
+        .build();
+    assert_eq!(sql, "REMOVE USER admin ON NAMESPACE");
+}
+
+#[test]
+fn v1_remove_scope_and_token() {
+    let v1 = QueryBuilder::with_version(SurrealV1);
+    assert_eq!(v1.remove().scope("account").build(), "REMOVE SCOPE account");
+    assert_eq!(
+        v1.remove().token("api", TokenBase::Namespace).build(),
+        "REMOVE TOKEN api ON NAMESPACE"
+    );
+}
@@ -0,0 +1,123 @@
+use surrealex::enums::{Condition, SelectionFields};
+use surrealex::types::script::Param;
+use surrealex::{QueryBuilder, SurrealV1, fields};
+
+#[test]
+fn empty_script() {
+    let script = QueryBuilder::script();
+    assert_eq!(script.build(), "");
+}
+
+#[test]
+fn let_binding_with_raw_value() {
+    let mut script = QueryBuilder::script();
+    let limit = script.r#let("limit", "10");
+    assert_eq!(limit.to_string(), "$limit");
+    assert_eq!(script.build(), "LET $limit = 10");
+}
+
+#[test]
+fn let_binding_strips_leading_dollar() {
+    let mut script = QueryBuilder::script();
+    let param = script.r#let("$name", "'Tobie'");
+    assert_eq!(param.name(), "name");
+    assert_eq!(script.build(), "LET $name = 'Tobie'");
+}
+
+#[test]
+fn handle_as_target() {
+    let mut script = QueryBuilder::script();
+    let stale = script.r#let(
+        "stale",
+        QueryBuilder::select(fields!("id"))
+            .from("session")
+            .r#where("expires < time::now()"),
+    );
+    let sql = script.statement(QueryBuilder::delete(&stale)).build();
+    assert_eq!(
+        sql,
+        "LET $stale = (SELECT id FROM session WHERE expires < time::now()); DELETE FROM $stale"
+    );
+}
+
+#[test]
+fn handle_as_set_value() {
+    let mut script = QueryBuilder::script();
+    let author = script.r#let("author", "user:tobie");
+    let sql = script
+        .statement(
+            QueryBuilder::create("post")
+                .set("author", &author)
+                .set("email", author.field("email")),
+        )
+        .build();
+    assert_eq!(
+        sql,
+        "LET $author = user:tobie; CREATE post SET author = $author, email = $author.email"
+    );
+}
+
+#[test]
+fn handle_in_condition() {
+    let mut script = QueryBuilder::script();
+    let is_admin = script.r#let("is_admin", "$auth.admin = true");
+    let min_age = script.r#let("min_age", "18");
+    let sql = script
+        .statement(
+            QueryBuilder::select(SelectionFields::All)
+                .from("user")
+                .r#where(Condition::from(&is_admin).or(format!("age >= {min_age}"))),
+        )
+        .build();
+    assert_eq!(
+        sql,
+        "LET $is_admin = $auth.admin = true; LET $min_age = 18; SELECT * FROM user WHERE ($is_admin OR age >= $min_age)"
+    );
+}
+
+#[test]
+fn set_value_accepts_subquery() {
+    let sql = QueryBuilder::update("stats:global")
+        .set(
+            "users",
+            QueryBuilder::select(fields!("count()")).from("user"),
+        )
+        .build();
+    assert_eq!(
+        sql,
+        "UPDATE stats:global SET users = (SELECT count() FROM user)"
+    );
+}
+
+#[test]
+fn statements_and_return() {
+    let mut script = QueryBuilder::script();
+    let user = script.r#let("user", QueryBuilder::create("user").set("name", "'Jaime'"));
+    let sql = script
+        .statement(QueryBuilder::relate(&user, "wrote", "post:1"))
+        .r#return(user.field("id"))
+        .build();
+    assert_eq!(
+        sql,
+        "LET $user = (CREATE user SET name = 'Jaime'); RELATE $user->wrote->post:1; RETURN $user.id"
+    );
+}
+
+#[test]
+fn existing_param_handle() {
+    let auth = Param::new("$auth");
+    let sql = QueryBuilder::update(auth.field("id"))
+        .set("last_seen", "time::now()")
+        .build();
+    assert_eq!(sql, "UPDATE $auth.id SET last_seen = time::now()");
+}
+
+#[test]
+fn versioned_builder_renders_script() {
+    let mut script = QueryBuilder::with_version(SurrealV1).script();
+    let user = script.r#let("user", "user:tobie");
+    let sql = script
+        .statement(QueryBuilder::with_version(SurrealV1).delete(user))
+        .build();
+    assert_eq!(sql, "LET $user = user:tobie; DELETE FROM $user");
+}
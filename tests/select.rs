@@ -1,5 +1,5 @@
 use surrealex::enums::{Condition, Direction, Sort};
-use surrealex::types::select::GraphTraversalParams;
+use surrealex::types::select::{GraphTraversalParams, SelectError};
 use surrealex::{QueryBuilder, SurrealV1};
 
 #[test]
@@ -709,3 +709,352 @@ fn where_order_limit_fetch_with_explain_full_builds() {
         "SELECT id FROM users WHERE active = true ORDER BY name ASC LIMIT 10 START AT 5 FETCH profile EXPLAIN FULL"
     );
 }
+
+#[test]
+fn group_by_builds() {
+    let sql = QueryBuilder::select(surrealex::fields!("country", ("count()", "total")))
+        .from("user")
+        .group_by(vec!["country"])
+        .build();
+
+    assert_eq!(
+        sql,
+        "SELECT country, count() AS total FROM user GROUP BY country"
+    );
+}
+
+#[test]
+fn group_by_accumulates_fields_builds() {
+    let sql = QueryBuilder::select(surrealex::fields!("country", "city", "math::sum(age)"))
+        .from("user")
+        .group_by(vec!["country"])
+        .group_by(vec!["city"])
+        .build();
+
+    assert_eq!(
+        sql,
+        "SELECT country, city, math::sum(age) FROM user GROUP BY country, city"
+    );
+}
+
+#[test]
+fn group_all_builds() {
+    let sql = QueryBuilder::select(surrealex::fields!(("count()", "total")))
+        .from("user")
+        .group_by(vec!["country"])
+        .group_all()
+        .build();
+
+    assert_eq!(sql, "SELECT count() AS total FROM user GROUP ALL");
+}
+
+#[test]
+fn group_renders_between_where_and_order_builds() {
+    let sql = QueryBuilder::select(surrealex::fields!("country", ("count()", "total")))
+        .from("user")
+        .r#where("active = true")
+        .order_by("total", Sort::Desc)
+        .limit(5)
+        .group_by(vec!["country"])
+        .build();
+
+    assert_eq!(
+        sql,
+        "SELECT country, count() AS total FROM user WHERE active = true GROUP BY country ORDER BY total DESC LIMIT 5"
+    );
+}
+
+#[test]
+fn try_build_accepts_grouped_and_aggregated_fields() {
+    let sql = QueryBuilder::select(surrealex::fields!(("country", "c"), ("count()", "total")))
+        .from("user")
+        .group_by(vec!["c"])
+        .try_build();
+
+    assert_eq!(
+        sql,
+        Ok("SELECT country AS c, count() AS total FROM user GROUP BY c".to_string())
+    );
+}
+
+#[test]
+fn try_build_rejects_ungrouped_fields() {
+    let result = QueryBuilder::select(surrealex::fields!("country", "name", "email", "count()"))
+        .from("user")
+        .group_by(vec!["country"])
+        .try_build();
+
+    assert_eq!(
+        result,
+        Err(SelectError::UngroupedFields(vec![
+            "name".to_string(),
+            "email".to_string()
+        ]))
+    );
+}
+
+#[test]
+fn try_build_rejects_star_with_group_all() {
+    let result = QueryBuilder::select(surrealex::enums::SelectionFields::All)
+        .from("user")
+        .group_all()
+        .try_build();
+
+    assert_eq!(
+        result,
+        Err(SelectError::UngroupedFields(vec!["*".to_string()]))
+    );
+}
+
+#[test]
+fn try_build_rejects_non_aggregate_function_calls() {
+    let result = QueryBuilder::select(surrealex::fields!("string::lowercase(name)"))
+        .from("t")
+        .group_all()
+        .try_build();
+
+    assert_eq!(
+        result,
+        Err(SelectError::UngroupedFields(vec![
+            "string::lowercase(name)".to_string()
+        ]))
+    );
+}
+
+#[test]
+fn try_build_rejects_compound_aggregate_expressions() {
+    let result = QueryBuilder::select(surrealex::fields!(
+        "count(x) + string::len(name)",
+        "math::sum(math::abs(total))",
+        "count(\"(\")"
+    ))
+    .from("t")
+    .group_all()
+    .try_build();
+
+    assert_eq!(
+        result,
+        Err(SelectError::UngroupedFields(vec![
+            "count(x) + string::len(name)".to_string()
+        ]))
+    );
+}
+
+#[test]
+fn try_build_accepts_grouped_function_calls_and_aggregates() {
+    let sql = QueryBuilder::select(surrealex::fields!(
+        "string::lowercase(name)",
+        ("math::sum(total)", "sum"),
+        ("array::group(tags)", "tags")
+    ))
+    .from("order")
+    .group_by(vec!["string::lowercase(name)"])
+    .try_build();
+
+    assert_eq!(
+        sql,
+        Ok("SELECT string::lowercase(name), math::sum(total) AS sum, array::group(tags) AS tags FROM order GROUP BY string::lowercase(name)".to_string())
+    );
+}
+
+#[test]
+fn group_by_ignores_empty_fields_builds() {
+    let sql = QueryBuilder::select(surrealex::fields!("a"))
+        .from("t")
+        .group_by(vec![])
+        .build();
+    assert_eq!(sql, "SELECT a FROM t");
+}
+
+#[test]
+fn try_build_without_group_never_fails() {
+    let result = QueryBuilder::select(surrealex::fields!("name"))
+        .from("user")
+        .try_build();
+
+    assert_eq!(result, Ok("SELECT name FROM user".to_string()));
+}
+
+#[test]
+fn split_builds() {
+    let sql = QueryBuilder::select(surrealex::fields!("name", "emails"))
+        .from("user")
+        .split(vec!["emails"])
+        .build();
+
+    assert_eq!(sql, "SELECT name, emails FROM user SPLIT emails");
+}
+
+#[test]
+fn split_accumulates_fields_builds() {
+    let sql = QueryBuilder::select(surrealex::enums::SelectionFields::All)
+        .from("user")
+        .split(vec!["emails"])
+        .split(vec!["phones"])
+        .build();
+
+    assert_eq!(sql, "SELECT * FROM user SPLIT emails, phones");
+}
+
+#[test]
+fn split_with_where_group_order_and_limit_builds() {
+    let sql = QueryBuilder::select(surrealex::fields!("emails", ("count()", "total")))
+        .from("user")
+        .limit(10)
+        .order_by("total", Sort::Desc)
+        .group_by(vec!["emails"])
+        .split(vec!["emails"])
+        .r#where("active = true")
+        .build();
+
+    assert_eq!(
+        sql,
+        "SELECT emails, count() AS total FROM user WHERE active = true SPLIT emails GROUP BY emails ORDER BY total DESC LIMIT 10"
+    );
+}
+
+#[test]
+fn split_field_can_be_grouped() {
+    let result = QueryBuilder::select(surrealex::fields!("tags", ("count()", "total")))
+        .from("post")
+        .split(vec!["tags"])
+        .group_by(vec!["tags"])
+        .try_build();
+
+    assert_eq!(
+        result,
+        Ok("SELECT tags, count() AS total FROM post SPLIT tags GROUP BY tags".to_string())
+    );
+}
+
+#[test]
+fn omit_builds() {
+    let sql = QueryBuilder::select(surrealex::fields!(*))
+        .omit(vec!["password", "secrets"])
+        .from("user")
+        .build();
+    assert_eq!(sql, "SELECT * OMIT password, secrets FROM user");
+}
+
+#[test]
+fn omit_accumulates_builds() {
+    let sql = QueryBuilder::select(surrealex::fields!(*))
+        .omit(vec!["password"])
+        .omit(vec!["opts.security"])
+        .from("user")
+        .r#where("active = true")
+        .build();
+    assert_eq!(
+        sql,
+        "SELECT * OMIT password, opts.security FROM user WHERE active = true"
+    );
+}
+
+#[test]
+fn select_value_builds() {
+    let sql = QueryBuilder::select_value("name").from("user").build();
+    assert_eq!(sql, "SELECT VALUE name FROM user");
+}
+
+#[test]
+fn select_value_drops_alias_builds() {
+    let sql = QueryBuilder::select_value(("name", "n"))
+        .from("user")
+        .limit(5)
+        .build();
+    assert_eq!(sql, "SELECT VALUE name FROM user LIMIT 5");
+}
+
+#[test]
+fn select_value_subquery_builds() {
+    let sql = QueryBuilder::select_value_subquery(QueryBuilder::select_value("name").from("user"))
+        .from("ONLY $input")
+        .build();
+    assert_eq!(
+        sql,
+        "SELECT VALUE (SELECT VALUE name FROM user) FROM ONLY $input"
+    );
+}
+
+#[test]
+fn select_value_subquery_replaces_expression_builds() {
+    let sql = QueryBuilder::select_value("id")
+        .subquery(QueryBuilder::select_value("name").from("user"))
+        .from("ONLY $input")
+        .build();
+    assert_eq!(
+        sql,
+        "SELECT VALUE (SELECT VALUE name FROM user) FROM ONLY $input"
+    );
+}
+
+#[test]
+fn select_value_traverse_builds() {
+    let sql = QueryBuilder::select_value_traverse(
+        GraphTraversalParams::start_out("wrote")
+            .step_out("book")
+            .fields(surrealex::fields!("title")),
+    )
+    .from("person")
+    .build();
+    assert_eq!(sql, "SELECT VALUE ->wrote->book.{title} FROM person");
+}
+
+#[test]
+fn select_value_traverse_drops_alias_builds() {
+    let sql =
+        QueryBuilder::select_value_traverse(GraphTraversalParams::start_out("wrote").alias("w"))
+            .from("person")
+            .build();
+    assert_eq!(sql, "SELECT VALUE ->wrote.* FROM person");
+}
+
+#[test]
+fn v1_select_value_traverse_builds() {
+    let sql = QueryBuilder::with_version(SurrealV1)
+        .select_value_traverse(
+            GraphTraversalParams::start_out("wrote")
+                .step_out("book")
+                .fields(surrealex::fields!(("title", "t"))),
+        )
+        .from("person")
+        .try_build();
+    assert_eq!(
+        sql,
+        Ok("SELECT VALUE ->wrote->book.title FROM person".to_string())
+    );
+}
+
+#[test]
+fn v1_select_value_traverse_keeps_first_field() {
+    let sql = QueryBuilder::with_version(SurrealV1)
+        .select_value_traverse(
+            GraphTraversalParams::start_out("wrote")
+                .step_out("book")
+                .fields(surrealex::fields!("title", "year")),
+        )
+        .from("person")
+        .try_build();
+    assert_eq!(
+        sql,
+        Ok("SELECT VALUE ->wrote->book.title FROM person".to_string())
+    );
+}
+
+#[test]
+fn v1_omit_with_graph_traverse_builds() {
+    let sql = QueryBuilder::with_version(SurrealV1)
+        .select(surrealex::fields!(*))
+        .graph_traverse(
+            GraphTraversalParams::start_out("friends")
+                .fields(surrealex::fields!(*))
+                .alias("friends"),
+        )
+        .omit(vec!["password"])
+        .from("user")
+        .build();
+    assert_eq!(
+        sql,
+        "SELECT *, ->friends.* AS friends OMIT password FROM user"
+    );
+}
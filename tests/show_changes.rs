@@ -0,0 +1,52 @@
+use surrealex::{QueryBuilder, SurrealV1};
+
+#[test]
+fn since_versionstamp() {
+    let sql = QueryBuilder::show_changes("reading")
+        .since_versionstamp(42)
+        .build();
+    assert_eq!(sql, "SHOW CHANGES FOR TABLE reading SINCE 42");
+}
+
+#[test]
+fn since_datetime_with_limit() {
+    let sql = QueryBuilder::show_changes("reading")
+        .since("2023-09-07T01:23:52Z")
+        .limit(10)
+        .build();
+    assert_eq!(
+        sql,
+        "SHOW CHANGES FOR TABLE reading SINCE d\"2023-09-07T01:23:52Z\" LIMIT 10"
+    );
+}
+
+#[test]
+fn later_since_replaces_earlier() {
+    let sql = QueryBuilder::show_changes("reading")
+        .since("2023-09-07T01:23:52Z")
+        .since_versionstamp(7)
+        .build();
+    assert_eq!(sql, "SHOW CHANGES FOR TABLE reading SINCE 7");
+}
+
+#[test]
+fn pairs_with_table_changefeed() {
+    let define = QueryBuilder::define_table("reading")
+        .changefeed("3d")
+        .build();
+    let show = QueryBuilder::show_changes("reading")
+        .since_versionstamp(0)
+        .build();
+    assert_eq!(define, "DEFINE TABLE reading CHANGEFEED 3d");
+    assert_eq!(show, "SHOW CHANGES FOR TABLE reading SINCE 0");
+}
+
+#[test]
+fn versioned_builder_renders_show_changes() {
+    let sql = QueryBuilder::with_version(SurrealV1)
+        .show_changes("reading")
+        .since_versionstamp(3)
+        .limit(5)
+        .build();
+    assert_eq!(sql, "SHOW CHANGES FOR TABLE reading SINCE 3 LIMIT 5");
+}
@@ -0,0 +1,126 @@
+use surrealex::enums::{Condition, SelectionFields};
+use surrealex::{QueryBuilder, SurrealV1};
+
+#[test]
+fn empty_transaction() {
+    let sql = QueryBuilder::transaction().build();
+    assert_eq!(sql, "BEGIN TRANSACTION; COMMIT TRANSACTION;");
+}
+
+#[test]
+fn transaction_with_mixed_builders() {
+    let sql = QueryBuilder::transaction()
+        .statement(QueryBuilder::create("user:one").set("name", "'One'"))
+        .statement(
+            QueryBuilder::insert("log")
+                .fields(vec!["event"])
+                .values(vec!["'signup'"]),
+        )
+        .statement(QueryBuilder::delete("session").r#where("expired = true"))
+        .statement(QueryBuilder::select(SelectionFields::All).from("user"))
+        .build();
+    assert_eq!(
+        sql,
+        "BEGIN TRANSACTION; CREATE user:one SET name = 'One'; INSERT INTO log (event) VALUES ('signup'); DELETE FROM session WHERE expired = true; SELECT * FROM user; COMMIT TRANSACTION;"
+    );
+}
+
+#[test]
+fn raw_statements() {
+    let sql = QueryBuilder::transaction()
+        .statement("LET $total = 100")
+        .statement(String::from("UPDATE stats SET total = $total"))
+        .build();
+    assert_eq!(
+        sql,
+        "BEGIN TRANSACTION; LET $total = 100; UPDATE stats SET total = $total; COMMIT TRANSACTION;"
+    );
+}
+
+#[test]
+fn cancel_transaction() {
+    let sql = QueryBuilder::transaction()
+        .statement(QueryBuilder::delete("user"))
+        .cancel()
+        .build();
+    assert_eq!(
+        sql,
+        "BEGIN TRANSACTION; DELETE FROM user; CANCEL TRANSACTION;"
+    );
+}
+
+#[test]
+fn conditional_throw() {
+    let sql = QueryBuilder::transaction()
+        .statement(QueryBuilder::update("account:one").set("balance", "balance - 100"))
+        .throw_if(
+            Condition::new("account:one.balance < 0").or("account:one.locked = true"),
+            "Transfer \"denied\"",
+        )
+        .build();
+    assert_eq!(
+        sql,
+        "BEGIN TRANSACTION; UPDATE account:one SET balance = balance - 100; IF (account:one.balance < 0 OR account:one.locked = true) { THROW \"Transfer \\\"denied\\\"\" }; COMMIT TRANSACTION;"
+    );
+}
+
+#[test]
+fn versioned_builder_renders_transaction() {
+    let sql = QueryBuilder::with_version(SurrealV1)
+        .transaction()
+        .statement(QueryBuilder::create("user"))
+        .build();
+    assert_eq!(sql, "BEGIN TRANSACTION; CREATE user; COMMIT TRANSACTION;");
+}
+
+#[test]
+fn v1_throw_if_renders_then_end_syntax() {
+    let sql = QueryBuilder::with_version(SurrealV1)
+        .transaction()
+        .statement(QueryBuilder::update("account:one").set("balance", "balance - 100"))
+        .throw_if("account:one.balance < 0", "neg")
+        .build();
+    assert_eq!(
+        sql,
+        "BEGIN TRANSACTION; UPDATE account:one SET balance = balance - 100; IF account:one.balance < 0 THEN (THROW \"neg\") END; COMMIT TRANSACTION;"
+    );
+}
+
+#[test]
+fn transaction_composes_in_scripts() {
+    let mut script = QueryBuilder::script();
+    let amount = script.r#let("amount", "100");
+    let sql = script
+        .statement(
+            QueryBuilder::transaction()
+                .statement(
+                    QueryBuilder::update("account:one")
+                        .set("balance", format!("balance - {amount}")),
+                )
+                .statement(
+                    QueryBuilder::update("account:two")
+                        .set("balance", format!("balance + {amount}")),
+                ),
+        )
+        .build();
+    assert_eq!(
+        sql,
+        "LET $amount = 100; BEGIN TRANSACTION; UPDATE account:one SET balance = balance - $amount; UPDATE account:two SET balance = balance + $amount; COMMIT TRANSACTION"
+    );
+}
+
+#[test]
+fn v1_transaction_renders_as_block_in_if_body() {
+    let sql = QueryBuilder::with_version(SurrealV1)
+        .r#if(
+            "$transfer",
+            QueryBuilder::with_version(SurrealV1)
+                .transaction()
+                .statement(QueryBuilder::create("log")),
+        )
+        .build();
+    assert_eq!(
+        sql,
+        "IF $transfer THEN { BEGIN TRANSACTION; CREATE log; COMMIT TRANSACTION } END"
+    );
+}
@@ -0,0 +1,249 @@
+use std::time::Duration;
+use surrealex::QueryBuilder;
+use surrealex::enums::Condition;
+
+#[test]
+fn build_update_targets() {
+    let sql = QueryBuilder::update("person").build();
+    assert_eq!(sql, "UPDATE person");
+}
+
+#[test]
+fn build_update_with_record_id() {
+    let sql = QueryBuilder::update("person:tobie").build();
+    assert_eq!(sql, "UPDATE person:tobie");
+}
+
+#[test]
+fn only_emits_update_only() {
+    let sql = QueryBuilder::update("person:tobie").only().build();
+    assert_eq!(sql, "UPDATE ONLY person:tobie");
+}
+
+#[test]
+fn set_single_field() {
+    let sql = QueryBuilder::update("person:tobie")
+        .set("name", "'Tobie'")
+        .build();
+    assert_eq!(sql, "UPDATE person:tobie SET name = 'Tobie'");
+}
+
+#[test]
+fn set_multiple_fields() {
+    let sql = QueryBuilder::update("person")
+        .set("active", "true")
+        .set("score", "score + 1")
+        .build();
+    assert_eq!(sql, "UPDATE person SET active = true, score = score + 1");
+}
+
+#[test]
+fn unset_multiple_fields() {
+    let sql = QueryBuilder::update("person")
+        .unset("temporary")
+        .unset("legacy_id")
+        .build();
+    assert_eq!(sql, "UPDATE person UNSET temporary, legacy_id");
+}
+
+#[test]
+fn content_clause() {
+    let sql = QueryBuilder::update("person:tobie")
+        .content("{ name: 'Tobie' }")
+        .build();
+    assert_eq!(sql, "UPDATE person:tobie CONTENT { name: 'Tobie' }");
+}
+
+#[test]
+fn merge_clause() {
+    let sql = QueryBuilder::update("person:tobie")
+        .merge("{ settings: { theme: 'dark' } }")
+        .build();
+    assert_eq!(
+        sql,
+        "UPDATE person:tobie MERGE { settings: { theme: 'dark' } }"
+    );
+}
+
+#[test]
+fn patch_clause() {
+    let sql = QueryBuilder::update("person:tobie")
+        .patch("[{ op: 'add', path: 'tags', value: 'rust' }]")
+        .build();
+    assert_eq!(
+        sql,
+        "UPDATE person:tobie PATCH [{ op: 'add', path: 'tags', value: 'rust' }]"
+    );
+}
+
+#[test]
+fn replace_clause() {
+    let sql = QueryBuilder::update("person:tobie")
+        .replace("{ name: 'Tobie' }")
+        .build();
+    assert_eq!(sql, "UPDATE person:tobie REPLACE { name: 'Tobie' }");
+}
+
+#[test]
+fn set_after_content_replaces_content() {
+    let sql = QueryBuilder::update("person")
+        .content("{ name: 'Tobie' }")
+        .set("name", "'Jaime'")
+        .build();
+    assert_eq!(sql, "UPDATE person SET name = 'Jaime'");
+}
+
+#[test]
+fn unset_after_set_replaces_set() {
+    let sql = QueryBuilder::update("person")
+        .set("name", "'Jaime'")
+        .unset("name")
+        .build();
+    assert_eq!(sql, "UPDATE person UNSET name");
+}
+
+#[test]
+fn merge_after_unset_replaces_unset() {
+    let sql = QueryBuilder::update("person")
+        .unset("name")
+        .merge("{ name: 'Jaime' }")
+        .build();
+    assert_eq!(sql, "UPDATE person MERGE { name: 'Jaime' }");
+}
+
+#[test]
+fn where_clauses_are_joined_with_and() {
+    let sql = QueryBuilder::update("person")
+        .set("active", "false")
+        .r#where("age < 18")
+        .r#where("verified = false")
+        .build();
+    assert_eq!(
+        sql,
+        "UPDATE person SET active = false WHERE age < 18 AND verified = false"
+    );
+}
+
+#[test]
+fn where_accepts_nested_conditions() {
+    let sql = QueryBuilder::update("person")
+        .set("flagged", "true")
+        .r#where(Condition::new("age < 18").or("status = 'banned'"))
+        .build();
+    assert_eq!(
+        sql,
+        "UPDATE person SET flagged = true WHERE (age < 18 OR status = 'banned')"
+    );
+}
+
+#[test]
+fn return_none_clause() {
+    let sql = QueryBuilder::update("person").return_none().build();
+    assert_eq!(sql, "UPDATE person RETURN NONE");
+}
+
+#[test]
+fn return_before_clause() {
+    let sql = QueryBuilder::update("person").return_before().build();
+    assert_eq!(sql, "UPDATE person RETURN BEFORE");
+}
+
+#[test]
+fn return_after_clause() {
+    let sql = QueryBuilder::update("person").return_after().build();
+    assert_eq!(sql, "UPDATE person RETURN AFTER");
+}
+
+#[test]
+fn return_diff_clause() {
+    let sql = QueryBuilder::update("person").return_diff().build();
+    assert_eq!(sql, "UPDATE person RETURN DIFF");
+}
+
+#[test]
+fn return_params_with_multiple_fields() {
+    let sql = QueryBuilder::update("person")
+        .set("age", "age + 1")
+        .return_params(vec!["name", "age"])
+        .build();
+    assert_eq!(sql, "UPDATE person SET age = age + 1 RETURN name, age");
+}
+
+#[test]
+fn return_value_clause() {
+    let sql = QueryBuilder::update("person")
+        .set("age", "age + 1")
+        .return_value("age")
+        .build();
+    assert_eq!(sql, "UPDATE person SET age = age + 1 RETURN VALUE age");
+}
+
+#[test]
+fn timeout_with_raw_string() {
+    let sql = QueryBuilder::update("person").timeout("5s").build();
+    assert_eq!(sql, "UPDATE person TIMEOUT 5s");
+}
+
+#[test]
+fn timeout_with_std_duration() {
+    let sql = QueryBuilder::update("person")
+        .timeout(Duration::from_secs(90))
+        .build();
+    assert_eq!(sql, "UPDATE person TIMEOUT 1m30s");
+}
+
+#[test]
+fn parallel_keyword() {
+    let sql = QueryBuilder::update("person").parallel().build();
+    assert_eq!(sql, "UPDATE person PARALLEL");
+}
+
+#[test]
+fn all_clauses_combined() {
+    let sql = QueryBuilder::update("person")
+        .only()
+        .merge("{ active: true }")
+        .r#where("age >= 18")
+        .return_after()
+        .timeout("2s")
+        .parallel()
+        .build();
+    assert_eq!(
+        sql,
+        "UPDATE ONLY person MERGE { active: true } WHERE age >= 18 RETURN AFTER TIMEOUT 2s PARALLEL"
+    );
+}
+
+#[test]
+fn clauses_are_emitted_in_correct_order_regardless_of_call_order() {
+    let sql = QueryBuilder::update("person")
+        .parallel()
+        .timeout("1s")
+        .return_diff()
+        .r#where("age > 10")
+        .set("name", "'Tobie'")
+        .only()
+        .build();
+    assert_eq!(
+        sql,
+        "UPDATE ONLY person SET name = 'Tobie' WHERE age > 10 RETURN DIFF TIMEOUT 1s PARALLEL"
+    );
+}
+
+#[test]
+fn versioned_builder_renders_update() {
+    let sql = QueryBuilder::with_version(surrealex::SurrealV1)
+        .update("person:tobie")
+        .set("name", "'Tobie'")
+        .build();
+    assert_eq!(sql, "UPDATE person:tobie SET name = 'Tobie'");
+}
+
+#[test]
+fn v1_replace_renders_content() {
+    let sql = QueryBuilder::with_version(surrealex::SurrealV1)
+        .update("person:tobie")
+        .replace("{ name: 'Tobie' }")
+        .build();
+    assert_eq!(sql, "UPDATE person:tobie CONTENT { name: 'Tobie' }");
+}
@@ -0,0 +1,164 @@
+use std::time::Duration;
+use surrealex::enums::Condition;
+use surrealex::{QueryBuilder, SurrealV2, SurrealV3};
+
+#[test]
+fn build_upsert_targets() {
+    let sql = QueryBuilder::upsert("person").build();
+    assert_eq!(sql, "UPSERT person");
+}
+
+#[test]
+fn only_emits_upsert_only() {
+    let sql = QueryBuilder::upsert("person:tobie").only().build();
+    assert_eq!(sql, "UPSERT ONLY person:tobie");
+}
+
+#[test]
+fn set_multiple_fields() {
+    let sql = QueryBuilder::upsert("person:tobie")
+        .set("name", "'Tobie'")
+        .set("company", "'SurrealDB'")
+        .build();
+    assert_eq!(
+        sql,
+        "UPSERT person:tobie SET name = 'Tobie', company = 'SurrealDB'"
+    );
+}
+
+#[test]
+fn unset_multiple_fields() {
+    let sql = QueryBuilder::upsert("person:tobie")
+        .unset("nickname")
+        .unset("age")
+        .build();
+    assert_eq!(sql, "UPSERT person:tobie UNSET nickname, age");
+}
+
+#[test]
+fn content_clause() {
+    let sql = QueryBuilder::upsert("person:tobie")
+        .content("{ name: 'Tobie' }")
+        .build();
+    assert_eq!(sql, "UPSERT person:tobie CONTENT { name: 'Tobie' }");
+}
+
+#[test]
+fn merge_clause() {
+    let sql = QueryBuilder::upsert("person:tobie")
+        .merge("{ active: true }")
+        .build();
+    assert_eq!(sql, "UPSERT person:tobie MERGE { active: true }");
+}
+
+#[test]
+fn patch_clause() {
+    let sql = QueryBuilder::upsert("person:tobie")
+        .patch("[{ op: 'replace', path: 'name', value: 'Tobie' }]")
+        .build();
+    assert_eq!(
+        sql,
+        "UPSERT person:tobie PATCH [{ op: 'replace', path: 'name', value: 'Tobie' }]"
+    );
+}
+
+#[test]
+fn replace_clause() {
+    let sql = QueryBuilder::upsert("person:tobie")
+        .replace("{ name: 'Tobie' }")
+        .build();
+    assert_eq!(sql, "UPSERT person:tobie REPLACE { name: 'Tobie' }");
+}
+
+#[test]
+fn content_after_set_replaces_set() {
+    let sql = QueryBuilder::upsert("person:tobie")
+        .set("name", "'Tobie'")
+        .content("{ name: 'Jaime' }")
+        .build();
+    assert_eq!(sql, "UPSERT person:tobie CONTENT { name: 'Jaime' }");
+}
+
+#[test]
+fn where_clauses_are_joined_with_and() {
+    let sql = QueryBuilder::upsert("person")
+        .set("synced", "true")
+        .r#where("email = 'tobie@surrealdb.com'")
+        .r#where(Condition::new("active = true").or("admin = true"))
+        .build();
+    assert_eq!(
+        sql,
+        "UPSERT person SET synced = true WHERE email = 'tobie@surrealdb.com' AND (active = true OR admin = true)"
+    );
+}
+
+#[test]
+fn return_variants() {
+    let sql = QueryBuilder::upsert("person:tobie").return_none().build();
+    assert_eq!(sql, "UPSERT person:tobie RETURN NONE");
+
+    let sql = QueryBuilder::upsert("person:tobie").return_before().build();
+    assert_eq!(sql, "UPSERT person:tobie RETURN BEFORE");
+
+    let sql = QueryBuilder::upsert("person:tobie").return_after().build();
+    assert_eq!(sql, "UPSERT person:tobie RETURN AFTER");
+
+    let sql = QueryBuilder::upsert("person:tobie").return_diff().build();
+    assert_eq!(sql, "UPSERT person:tobie RETURN DIFF");
+}
+
+#[test]
+fn return_params_and_value() {
+    let sql = QueryBuilder::upsert("person:tobie")
+        .return_params(vec!["id", "name"])
+        .build();
+    assert_eq!(sql, "UPSERT person:tobie RETURN id, name");
+
+    let sql = QueryBuilder::upsert("person:tobie")
+        .return_value("name")
+        .build();
+    assert_eq!(sql, "UPSERT person:tobie RETURN VALUE name");
+}
+
+#[test]
+fn timeout_with_std_duration() {
+    let sql = QueryBuilder::upsert("person:tobie")
+        .timeout(Duration::from_millis(1500))
+        .build();
+    assert_eq!(sql, "UPSERT person:tobie TIMEOUT 1s500ms");
+}
+
+#[test]
+fn all_clauses_combined() {
+    let sql = QueryBuilder::upsert("person:tobie")
+        .only()
+        .set("name", "'Tobie'")
+        .r#where("age > 18")
+        .return_after()
+        .timeout("5s")
+        .parallel()
+        .build();
+    assert_eq!(
+        sql,
+        "UPSERT ONLY person:tobie SET name = 'Tobie' WHERE age > 18 RETURN AFTER TIMEOUT 5s PARALLEL"
+    );
+}
+
+#[test]
+fn explicit_v2_renders_upsert() {
+    let sql = QueryBuilder::with_version(SurrealV2)
+        .upsert("person:tobie")
+        .merge("{ active: true }")
+        .build();
+    assert_eq!(sql, "UPSERT person:tobie MERGE { active: true }");
+}
+
+#[test]
+fn explicit_v3_renders_upsert() {
+    let sql = QueryBuilder::with_version(SurrealV3)
+        .upsert("person")
+        .set("active", "true")
+        .r#where("age > 18")
+        .build();
+    assert_eq!(sql, "UPSERT person SET active = true WHERE age > 18");
+}
@@ -0,0 +1,94 @@
+use std::time::Duration;
+
+use surrealex::traits::IntoStatement;
+use surrealex::types::utility::QueryOption;
+use surrealex::{QueryBuilder, SurrealV1, SurrealV2};
+
+#[test]
+fn use_namespace_and_database() {
+    assert_eq!(
+        QueryBuilder::r#use().ns("acme").db("app").build(),
+        "USE NS acme DB app"
+    );
+    assert_eq!(QueryBuilder::r#use().ns("acme").build(), "USE NS acme");
+    assert_eq!(QueryBuilder::r#use().db("app").build(), "USE DB app");
+}
+
+#[test]
+fn sleep_with_duration() {
+    assert_eq!(QueryBuilder::sleep("500ms").build(), "SLEEP 500ms");
+    assert_eq!(
+        QueryBuilder::sleep(Duration::from_secs(90)).build(),
+        "SLEEP 1m30s"
+    );
+}
+
+#[test]
+fn option_statements() {
+    assert_eq!(
+        QueryBuilder::option(QueryOption::Import, true).build(),
+        "OPTION IMPORT"
+    );
+    assert_eq!(
+        QueryBuilder::option(QueryOption::Events, false).build(),
+        "OPTION EVENTS = false"
+    );
+    assert_eq!(
+        QueryBuilder::option(QueryOption::Fields, false).build(),
+        "OPTION FIELDS = false"
+    );
+    assert_eq!(
+        QueryBuilder::option(QueryOption::Tables, true).build(),
+        "OPTION TABLES"
+    );
+}
+
+#[test]
+fn rebuild_index() {
+    assert_eq!(
+        QueryBuilder::rebuild_index("unique_email", "user").build(),
+        "REBUILD INDEX unique_email ON user"
+    );
+    assert_eq!(
+        QueryBuilder::rebuild_index("unique_email", "user")
+            .if_exists()
+            .build(),
+        "REBUILD INDEX IF EXISTS unique_email ON user"
+    );
+}
+
+#[test]
+fn utility_statements_compose_in_scripts() {
+    let sql = QueryBuilder::script()
+        .statement(QueryBuilder::r#use().ns("acme").db("app"))
+        .statement(QueryBuilder::option(QueryOption::Import, true))
+        .statement(QueryBuilder::rebuild_index("idx", "post").if_exists())
+        .statement(QueryBuilder::sleep("1s"))
+        .build();
+    assert_eq!(
+        sql,
+        "USE NS acme DB app; OPTION IMPORT; REBUILD INDEX IF EXISTS idx ON post; SLEEP 1s"
+    );
+    assert_eq!(QueryBuilder::sleep("1s").into_statement(), "SLEEP 1s");
+}
+
+#[test]
+fn versioned_builder_renders_utility_statements() {
+    assert_eq!(
+        QueryBuilder::with_version(SurrealV1)
+            .r#use()
+            .ns("acme")
+            .build(),
+        "USE NS acme"
+    );
+    assert_eq!(
+        QueryBuilder::with_version(SurrealV1).sleep("2s").build(),
+        "SLEEP 2s"
+    );
+    assert_eq!(
+        QueryBuilder::with_version(SurrealV2)
+            .rebuild_index("idx", "post")
+            .build(),
+        "REBUILD INDEX idx ON post"
+    );
+}